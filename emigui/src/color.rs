@@ -1,3 +1,5 @@
+use crate::math::clamp;
+
 /// 0-255 sRGBA
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Color {
@@ -16,6 +18,90 @@ impl Color {
             a: 0,
         }
     }
+
+    /// Hue, saturation and value, all in 0-1.
+    /// Computed on the sRGB values, so good for color pickers, not for color math.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let range = max - min;
+
+        let h = if range == 0.0 {
+            0.0
+        } else if max == r {
+            ((g - b) / range).rem_euclid(6.0) / 6.0
+        } else if max == g {
+            ((b - r) / range + 2.0) / 6.0
+        } else {
+            ((r - g) / range + 4.0) / 6.0
+        };
+        let s = if max == 0.0 { 0.0 } else { range / max };
+        (h, s, max)
+    }
+
+    /// Hue, saturation, value and alpha, all in 0-1.
+    pub fn to_hsva(self) -> (f32, f32, f32, f32) {
+        let (h, s, v) = self.to_hsv();
+        (h, s, v, self.a as f32 / 255.0)
+    }
+
+    /// From hue, saturation and value, all in 0-1 (the hue wraps around).
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+        Color::from_hsva(h, s, v, 1.0)
+    }
+
+    /// From hue, saturation, value and alpha, all in 0-1 (the hue wraps around).
+    pub fn from_hsva(h: f32, s: f32, v: f32, a: f32) -> Color {
+        let h = h.rem_euclid(1.0) * 6.0;
+        let sector = h.floor();
+        let f = h - sector;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+        let (r, g, b) = match sector as i32 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+        srgba(
+            u8_from_f32(r),
+            u8_from_f32(g),
+            u8_from_f32(b),
+            u8_from_f32(a),
+        )
+    }
+
+    /// e.g. "#ff8000", or "#ff800080" if not opaque.
+    pub fn to_hex(self) -> String {
+        if self.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    /// Parse "#rrggbb" or "#rrggbbaa". The "#" is optional.
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let byte = |i: usize| -> Option<u8> { u8::from_str_radix(hex.get(i..i + 2)?, 16).ok() };
+        match hex.len() {
+            6 => Some(srgba(byte(0)?, byte(2)?, byte(4)?, 255)),
+            8 => Some(srgba(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+            _ => None,
+        }
+    }
+}
+
+/// 0-1 to 0-255
+fn u8_from_f32(x: f32) -> u8 {
+    (clamp(x, 0.0..=1.0) * 255.0).round() as u8
 }
 
 pub const fn srgba(r: u8, g: u8, b: u8, a: u8) -> Color {
@@ -1,13 +1,32 @@
 pub mod collapsing_header;
+pub mod dock;
 pub mod floating;
 pub mod frame;
+pub mod menu;
+pub mod modal;
+pub mod panel;
+pub mod popup;
 pub mod resize;
 pub mod scroll_area;
+pub mod table;
+pub(crate) mod tooltip;
+pub mod tree_view;
 pub mod window;
 
 pub use {
-    collapsing_header::CollapsingHeader, floating::Floating, frame::Frame, resize::Resize,
-    scroll_area::ScrollArea, window::Window,
+    collapsing_header::CollapsingHeader,
+    dock::DockArea,
+    floating::Floating,
+    frame::Frame,
+    menu::{Menu, MenuBar, MenuItem},
+    modal::Modal,
+    panel::{BottomPanel, CentralPanel, SidePanel, TopPanel},
+    popup::{Placement, Popup},
+    resize::Resize,
+    scroll_area::ScrollArea,
+    table::{Column, SortDirection, Table},
+    tree_view::{TreeBody, TreeView},
+    window::Window,
 };
 
 // TODO
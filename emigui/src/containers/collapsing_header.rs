@@ -15,6 +15,53 @@ impl Default for State {
     }
 }
 
+impl State {
+    pub fn toggle(&mut self, region: &Region) {
+        self.open = !self.open;
+        self.toggle_time = region.input().time;
+    }
+
+    fn time_since_toggle(&self, region: &Region) -> f32 {
+        (region.input().time - self.toggle_time) as f32
+    }
+
+    /// Are we open, or still animating the closing?
+    pub fn is_visible(&self, region: &Region) -> bool {
+        self.open || self.time_since_toggle(region) < region.style().animation_time
+    }
+
+    /// Add the contents, clipping them while the opening or closing is animated.
+    pub fn add_contents(&self, region: &mut Region, add_contents: impl FnOnce(&mut Region)) {
+        let animation_time = region.style().animation_time;
+        let time_since_toggle = self.time_since_toggle(region);
+        if time_since_toggle < animation_time {
+            let max_height = if self.open {
+                remap(
+                    time_since_toggle,
+                    0.0..=animation_time,
+                    // Get instant feedback, and we don't expect to get bigger than this
+                    100.0..=1500.0,
+                )
+            } else {
+                remap_clamp(
+                    time_since_toggle,
+                    0.0..=animation_time,
+                    // TODO: state.open_height
+                    50.0..=0.0,
+                )
+            };
+
+            region.clip_rect.max.y = region.clip_rect.max.y.min(region.cursor.y + max_height);
+
+            add_contents(region);
+
+            region.child_bounds.max.y = region.child_bounds.max.y.min(region.cursor.y + max_height);
+        } else if self.open {
+            add_contents(region);
+        }
+    }
+}
+
 pub struct CollapsingHeader {
     title: String,
     default_open: bool,
@@ -65,8 +112,7 @@ impl CollapsingHeader {
                 ..Default::default()
             });
             if interact.clicked {
-                state.open = !state.open;
-                state.toggle_time = region.ctx.input.time;
+                state.toggle(region);
             }
             *state
         };
@@ -90,36 +136,8 @@ impl CollapsingHeader {
             Some(region.style.interact_stroke_color(&interact)),
         );
 
-        let animation_time = region.style().animation_time;
-        let time_since_toggle = (region.ctx.input.time - state.toggle_time) as f32;
-        let animate = time_since_toggle < animation_time;
-        if animate {
-            region.indent(id, |region| {
-                let max_height = if state.open {
-                    remap(
-                        time_since_toggle,
-                        0.0..=animation_time,
-                        // Get instant feedback, and we don't expect to get bigger than this
-                        100.0..=1500.0,
-                    )
-                } else {
-                    remap_clamp(
-                        time_since_toggle,
-                        0.0..=animation_time,
-                        // TODO: state.open_height
-                        50.0..=0.0,
-                    )
-                };
-
-                region.clip_rect.max.y = region.clip_rect.max.y.min(region.cursor.y + max_height);
-
-                add_contents(region);
-
-                region.child_bounds.max.y =
-                    region.child_bounds.max.y.min(region.cursor.y + max_height);
-            });
-        } else if state.open {
-            region.indent(id, add_contents);
+        if state.is_visible(region) {
+            region.indent(id, |region| state.add_contents(region, add_contents));
         }
 
         region.response(interact)
@@ -0,0 +1,718 @@
+//! A dock area is a part of the screen that windows can be docked into.
+//!
+//! While dragging a `Window`, drop targets are shown over the dock area:
+//! drop the window on the middle target of a docked window to add it as a tab,
+//! or on one of the targets around it to split the space.
+//! The targets close to the edges of the dock area split the whole area.
+//! Drag a tab away from its tab bar to make it a floating window again.
+//!
+//! The layout is a tree of splits and tab groups stored in `Memory`.
+//! It can be read and written with `Memory::dock_state` and `Memory::set_dock_state`,
+//! e.g. to persist the layout between runs.
+//!
+//! Show the `DockArea` before any windows each frame.
+
+use std::{hash::Hash, sync::Arc};
+
+use crate::{containers::floating, layout::Direction, *};
+
+/// A docked window, shown as a tab.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Tab {
+    /// Same as the id of the `Window`.
+    pub id: Id,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Node {
+    Empty,
+    /// One or more windows on top of each other, with a tab bar to choose between them.
+    Tabs {
+        tabs: Vec<Tab>,
+        active: usize,
+    },
+    /// Two nodes next to each other (`Horizontal`) or on top of each other (`Vertical`).
+    Split {
+        dir: Direction,
+        /// How much of the space goes to `first`, in the range `[0, 1]`.
+        fraction: f32,
+        first: Box<Node>,
+        second: Box<Node>,
+    },
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Node::Empty
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct State {
+    pub root: Node,
+
+    /// Where the dock area was last shown.
+    #[serde(skip)]
+    rect: Rect,
+}
+
+/// Where a docked window is shown this frame.
+/// `rect` is `None` if the window is in a tab that is not selected.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DockedWindow {
+    pub dock_id: Id,
+    pub rect: Option<Rect>,
+}
+
+/// Where in a node a dragged window will end up if dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DropZone {
+    /// Add as a tab
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Path from the root to a node. `false` means `first`, `true` means `second`.
+type NodePath = Vec<bool>;
+
+// Size of the squares you drop windows onto.
+const DROP_TARGET_SIZE: f32 = 32.0;
+
+// Space between the two halves of a split.
+const SPLITTER_WIDTH: f32 = 4.0;
+
+// ----------------------------------------------------------------------------
+
+impl Node {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Node::Empty)
+    }
+
+    pub fn contains(&self, id: Id) -> bool {
+        match self {
+            Node::Empty => false,
+            Node::Tabs { tabs, .. } => tabs.iter().any(|tab| tab.id == id),
+            Node::Split { first, second, .. } => first.contains(id) || second.contains(id),
+        }
+    }
+
+    fn tab_mut(&mut self, id: Id) -> Option<&mut Tab> {
+        match self {
+            Node::Empty => None,
+            Node::Tabs { tabs, .. } => tabs.iter_mut().find(|tab| tab.id == id),
+            Node::Split { first, second, .. } => {
+                first.tab_mut(id).or_else(move || second.tab_mut(id))
+            }
+        }
+    }
+
+    /// Remove the tab with the given id, collapsing splits that become empty.
+    pub fn remove(&mut self, id: Id) -> bool {
+        let removed = match self {
+            Node::Empty => false,
+            Node::Tabs { tabs, active } => {
+                if let Some(index) = tabs.iter().position(|tab| tab.id == id) {
+                    tabs.remove(index);
+                    if *active > index || *active >= tabs.len() {
+                        *active = active.saturating_sub(1);
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+            Node::Split { first, second, .. } => first.remove(id) || second.remove(id),
+        };
+
+        if removed {
+            let replacement = match self {
+                Node::Tabs { tabs, .. } if tabs.is_empty() => Some(Node::Empty),
+                Node::Split { first, second, .. } if first.is_empty() => {
+                    Some(std::mem::take(second.as_mut()))
+                }
+                Node::Split { first, second, .. } if second.is_empty() => {
+                    Some(std::mem::take(first.as_mut()))
+                }
+                _ => None,
+            };
+            if let Some(replacement) = replacement {
+                *self = replacement;
+            }
+        }
+        removed
+    }
+
+    fn node_mut(&mut self, path: &[bool]) -> &mut Node {
+        match (path.split_first(), self) {
+            (Some((&go_second, rest)), Node::Split { first, second, .. }) => {
+                if go_second {
+                    second.node_mut(rest)
+                } else {
+                    first.node_mut(rest)
+                }
+            }
+            (_, node) => node,
+        }
+    }
+
+    fn insert(&mut self, zone: DropZone, tab: Tab) {
+        let old = std::mem::take(self);
+        *self = match (zone, old) {
+            (DropZone::Center, Node::Tabs { mut tabs, .. }) => {
+                tabs.push(tab);
+                let active = tabs.len() - 1;
+                Node::Tabs { tabs, active }
+            }
+            (_, Node::Empty) => single_tab(tab),
+            (DropZone::Center, old) | (DropZone::Right, old) => {
+                split(Direction::Horizontal, old, single_tab(tab))
+            }
+            (DropZone::Left, old) => split(Direction::Horizontal, single_tab(tab), old),
+            (DropZone::Top, old) => split(Direction::Vertical, single_tab(tab), old),
+            (DropZone::Bottom, old) => split(Direction::Vertical, old, single_tab(tab)),
+        };
+    }
+
+    /// The rectangles of all tab groups, with the paths to them.
+    fn leaves(&self, rect: Rect, path: &mut NodePath, out: &mut Vec<(NodePath, Rect)>) {
+        match self {
+            Node::Empty => {}
+            Node::Tabs { .. } => out.push((path.clone(), rect)),
+            Node::Split {
+                dir,
+                fraction,
+                first,
+                second,
+            } => {
+                let (first_rect, second_rect, _) = split_rect(rect, *dir, *fraction);
+                path.push(false);
+                first.leaves(first_rect, path, out);
+                path.pop();
+                path.push(true);
+                second.leaves(second_rect, path, out);
+                path.pop();
+            }
+        }
+    }
+}
+
+fn single_tab(tab: Tab) -> Node {
+    Node::Tabs {
+        tabs: vec![tab],
+        active: 0,
+    }
+}
+
+fn split(dir: Direction, first: Node, second: Node) -> Node {
+    Node::Split {
+        dir,
+        fraction: 0.5,
+        first: Box::new(first),
+        second: Box::new(second),
+    }
+}
+
+/// Returns the rects of the two halves and of the splitter between them.
+fn split_rect(rect: Rect, dir: Direction, fraction: f32) -> (Rect, Rect, Rect) {
+    match dir {
+        Direction::Horizontal => {
+            let x = lerp(rect.left()..=rect.right(), fraction);
+            (
+                Rect::from_min_max(rect.min, pos2(x - SPLITTER_WIDTH / 2.0, rect.bottom())),
+                Rect::from_min_max(pos2(x + SPLITTER_WIDTH / 2.0, rect.top()), rect.max),
+                Rect::from_min_max(
+                    pos2(x - SPLITTER_WIDTH / 2.0, rect.top()),
+                    pos2(x + SPLITTER_WIDTH / 2.0, rect.bottom()),
+                ),
+            )
+        }
+        Direction::Vertical => {
+            let y = lerp(rect.top()..=rect.bottom(), fraction);
+            (
+                Rect::from_min_max(rect.min, pos2(rect.right(), y - SPLITTER_WIDTH / 2.0)),
+                Rect::from_min_max(pos2(rect.left(), y + SPLITTER_WIDTH / 2.0), rect.max),
+                Rect::from_min_max(
+                    pos2(rect.left(), y - SPLITTER_WIDTH / 2.0),
+                    pos2(rect.right(), y + SPLITTER_WIDTH / 2.0),
+                ),
+            )
+        }
+    }
+}
+
+fn zone_rect(rect: Rect, zone: DropZone) -> Rect {
+    let half = rect.size() * 0.5;
+    match zone {
+        DropZone::Center => rect,
+        DropZone::Left => Rect::from_min_size(rect.min, vec2(half.x, rect.height())),
+        DropZone::Right => Rect::from_min_max(pos2(rect.center().x, rect.top()), rect.max),
+        DropZone::Top => Rect::from_min_size(rect.min, vec2(rect.width(), half.y)),
+        DropZone::Bottom => Rect::from_min_max(pos2(rect.left(), rect.center().y), rect.max),
+    }
+}
+
+/// A place to drop a dragged window.
+struct DropTarget {
+    path: NodePath,
+    zone: DropZone,
+    /// Drop the window here...
+    target_rect: Rect,
+    /// ...and it ends up here.
+    preview_rect: Rect,
+}
+
+impl State {
+    /// All the places a dragged window can be dropped:
+    /// small squares in the middle of each tab group and the middle of each edge of the dock area.
+    fn drop_targets(&self) -> Vec<DropTarget> {
+        let area = self.rect;
+        let size = Vec2::splat(DROP_TARGET_SIZE);
+        let mut targets = vec![];
+
+        if self.root.is_empty() {
+            targets.push(DropTarget {
+                path: vec![],
+                zone: DropZone::Center,
+                target_rect: Rect::from_center_size(area.center(), size),
+                preview_rect: area,
+            });
+            return targets;
+        }
+
+        // Split the whole area:
+        let inset = DROP_TARGET_SIZE;
+        let area_edges = [
+            (DropZone::Left, pos2(area.left() + inset, area.center().y)),
+            (DropZone::Right, pos2(area.right() - inset, area.center().y)),
+            (DropZone::Top, pos2(area.center().x, area.top() + inset)),
+            (
+                DropZone::Bottom,
+                pos2(area.center().x, area.bottom() - inset),
+            ),
+        ];
+        for &(zone, center) in &area_edges {
+            targets.push(DropTarget {
+                path: vec![],
+                zone,
+                target_rect: Rect::from_center_size(center, size),
+                preview_rect: zone_rect(area, zone),
+            });
+        }
+
+        // Add as a tab, or split a tab group:
+        let mut leaves = vec![];
+        self.root.leaves(area, &mut vec![], &mut leaves);
+        let step = 1.25 * DROP_TARGET_SIZE;
+        for (path, rect) in leaves {
+            let center = rect.center();
+            let zones = [
+                (DropZone::Center, center),
+                (DropZone::Left, center - vec2(step, 0.0)),
+                (DropZone::Right, center + vec2(step, 0.0)),
+                (DropZone::Top, center - vec2(0.0, step)),
+                (DropZone::Bottom, center + vec2(0.0, step)),
+            ];
+            for &(zone, center) in &zones {
+                targets.push(DropTarget {
+                    path: path.clone(),
+                    zone,
+                    target_rect: Rect::from_center_size(center, size),
+                    preview_rect: zone_rect(rect, zone),
+                });
+            }
+        }
+
+        targets
+    }
+
+    /// Where would something dropped at `pos` end up?
+    fn drop_target(&self, pos: Pos2) -> Option<DropTarget> {
+        self.drop_targets()
+            .into_iter()
+            .find(|target| target.target_rect.contains(pos))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// An area of the screen where windows can be docked.
+/// By default it covers what is left of the screen after panels.
+#[derive(Clone, Copy, Debug)]
+pub struct DockArea {
+    id: Id,
+    rect: Option<Rect>,
+}
+
+impl DockArea {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            rect: None,
+        }
+    }
+
+    /// Cover this part of the screen instead of `Context::available_rect()`.
+    pub fn rect(mut self, rect: Rect) -> Self {
+        self.rect = Some(rect);
+        self
+    }
+}
+
+impl DockArea {
+    pub fn show(self, ctx: &Arc<Context>) {
+        let rect = self.rect.unwrap_or_else(|| ctx.available_rect());
+        let id = ctx.register_unique_id(self.id, "DockArea", rect.min);
+
+        let mut state = ctx.memory.lock().dock_state(id).unwrap_or_default();
+        state.rect = rect;
+
+        let mut undock = None;
+        ui_node(ctx, id, &mut state.root, rect, &mut vec![], &mut undock);
+
+        if let Some((tab_id, size)) = undock {
+            state.root.remove(tab_id);
+            if let Some(mouse_pos) = ctx.input.mouse_pos {
+                // Turn it into a floating window and keep dragging it:
+                let pos = mouse_pos - vec2(size.x / 2.0, 8.0);
+                let mut memory = ctx.memory.lock();
+                memory.set_floating_state(
+                    tab_id,
+                    floating::State {
+                        pos,
+                        size,
+                        order: Default::default(),
+                    },
+                );
+                memory.move_floating_to_top(tab_id);
+                memory.active_id = Some(tab_id.with("move"));
+            }
+        }
+
+        // Show where a dragged window can be dropped, and where it would end up:
+        let dragged_floating = ctx.memory.lock().dragged_floating;
+        if let (Some(_), Some(mouse_pos)) = (dragged_floating, ctx.input.mouse_pos) {
+            let style = ctx.style();
+            for target in state.drop_targets() {
+                let hovered = target.target_rect.contains(mouse_pos);
+                if hovered {
+                    ctx.add_paint_cmd(
+                        Layer::Popup,
+                        PaintCmd::Rect {
+                            rect: target.preview_rect,
+                            corner_radius: style.window.corner_radius,
+                            fill_color: Some(color::srgba(100, 100, 150, 64)),
+                            outline: Some(Outline::new(1.0, color::LIGHT_BLUE)),
+                        },
+                    );
+                }
+                let interact = InteractInfo {
+                    hovered,
+                    rect: target.target_rect,
+                    ..Default::default()
+                };
+                ctx.add_paint_cmd(
+                    Layer::Popup,
+                    PaintCmd::Rect {
+                        rect: target.target_rect,
+                        corner_radius: style.interact_corner_radius(&interact),
+                        fill_color: style.interact_fill_color(&interact),
+                        outline: Some(Outline::new(1.0, color::LIGHT_BLUE)),
+                    },
+                );
+                if target.zone != DropZone::Center {
+                    // Mark which half the window will take up:
+                    ctx.add_paint_cmd(
+                        Layer::Popup,
+                        PaintCmd::Rect {
+                            rect: zone_rect(target.target_rect.expand(-4.0), target.zone),
+                            corner_radius: 0.0,
+                            fill_color: Some(color::LIGHT_BLUE),
+                            outline: None,
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut memory = ctx.memory.lock();
+        memory.docks_this_frame.push(id);
+        memory
+            .docked_windows
+            .retain(|_, docked| docked.dock_id != id);
+        let bar_height = tab_bar_height(&ctx.style());
+        collect_docked(
+            id,
+            &state.root,
+            rect,
+            bar_height,
+            &mut memory.docked_windows,
+        );
+        memory.set_dock_state(id, state);
+    }
+}
+
+/// The title of a window can change from frame to frame, so we keep its tab up to date.
+pub(crate) fn update_title(ctx: &Arc<Context>, dock_id: Id, window_id: Id, title: &str) {
+    let mut memory = ctx.memory.lock();
+    let tab = memory
+        .docks
+        .get_mut(&dock_id)
+        .and_then(|state| state.root.tab_mut(window_id));
+    if let Some(tab) = tab {
+        if tab.title != title {
+            tab.title = title.to_owned();
+            ctx.request_repaint(); // The tab bar has already been painted this frame
+        }
+    }
+}
+
+/// Remove the tab of a docked window that has been closed.
+pub(crate) fn close_window(ctx: &Arc<Context>, window_id: Id) {
+    let memory = &mut *ctx.memory.lock();
+    if let Some(docked) = memory.docked_windows.remove(&window_id) {
+        if let Some(state) = memory.docks.get_mut(&docked.dock_id) {
+            state.root.remove(window_id);
+            ctx.request_repaint(); // The tab bar has already been painted this frame
+        }
+    }
+}
+
+/// Dock the window if it was just dropped onto a dock area.
+/// Returns true if it was docked.
+pub(crate) fn drop_window(ctx: &Arc<Context>, window_id: Id, title: &str) -> bool {
+    let mouse_pos = match ctx.input.mouse_pos {
+        Some(mouse_pos) if ctx.input.mouse_released => mouse_pos,
+        _ => return false,
+    };
+
+    let mut memory = ctx.memory.lock();
+    if memory.dragged_floating != Some(window_id) {
+        return false;
+    }
+
+    // Overlapping dock areas: the one on top gets it.
+    let dock_ids: Vec<Id> = memory.docks_this_frame.iter().rev().cloned().collect();
+    for dock_id in dock_ids {
+        let mut state = match memory.docks.get(&dock_id) {
+            Some(state) => state.clone(),
+            None => continue,
+        };
+        if state.root.contains(window_id) {
+            continue;
+        }
+        if let Some(target) = state.drop_target(mouse_pos) {
+            let tab = Tab {
+                id: window_id,
+                title: title.to_owned(),
+            };
+            state.root.node_mut(&target.path).insert(target.zone, tab);
+            memory.set_dock_state(dock_id, state);
+            memory.remove_floating(window_id);
+            memory.dragged_floating = None;
+            return true;
+        }
+    }
+    false
+}
+
+fn collect_docked(
+    dock_id: Id,
+    node: &Node,
+    rect: Rect,
+    bar_height: f32,
+    out: &mut std::collections::HashMap<Id, DockedWindow>,
+) {
+    match node {
+        Node::Empty => {}
+        Node::Tabs { tabs, active } => {
+            let contents_rect =
+                Rect::from_min_max(pos2(rect.left(), rect.top() + bar_height), rect.max);
+            for (i, tab) in tabs.iter().enumerate() {
+                let rect = if i == *active {
+                    Some(contents_rect)
+                } else {
+                    None
+                };
+                out.insert(tab.id, DockedWindow { dock_id, rect });
+            }
+        }
+        Node::Split {
+            dir,
+            fraction,
+            first,
+            second,
+        } => {
+            let (first_rect, second_rect, _) = split_rect(rect, *dir, *fraction);
+            collect_docked(dock_id, first, first_rect, bar_height, out);
+            collect_docked(dock_id, second, second_rect, bar_height, out);
+        }
+    }
+}
+
+fn tab_bar_height(style: &Style) -> f32 {
+    style.clickable_diameter + 2.0 * style.button_padding.y
+}
+
+/// Paint splitters and tab bars, and handle their interaction.
+fn ui_node(
+    ctx: &Arc<Context>,
+    dock_id: Id,
+    node: &mut Node,
+    rect: Rect,
+    path: &mut NodePath,
+    undock: &mut Option<(Id, Vec2)>,
+) {
+    let layer = Layer::Background;
+    let style = ctx.style();
+
+    match node {
+        Node::Empty => {}
+        Node::Split {
+            dir,
+            fraction,
+            first,
+            second,
+        } => {
+            let (_, _, splitter_rect) = split_rect(rect, *dir, *fraction);
+            let grab_rect = splitter_rect.expand(style.resize_grab_radius - SPLITTER_WIDTH / 2.0);
+            let interact = ctx.interact(
+                layer,
+                &Rect::everything(),
+                &grab_rect,
+                Some(dock_id.with("splitter").with(&path)),
+            );
+            if interact.active {
+                if let Some(mouse_pos) = ctx.input.mouse_pos {
+                    let new_fraction = match dir {
+                        Direction::Horizontal => remap(mouse_pos.x, rect.range_x(), 0.0..=1.0),
+                        Direction::Vertical => remap(mouse_pos.y, rect.range_y(), 0.0..=1.0),
+                    };
+                    *fraction = clamp(new_fraction, 0.1..=0.9);
+                }
+            }
+            if interact.hovered || interact.active {
+                ctx.output.lock().cursor_icon = match dir {
+                    Direction::Horizontal => CursorIcon::ResizeHorizontal,
+                    Direction::Vertical => CursorIcon::ResizeVertical,
+                };
+            }
+
+            let (first_rect, second_rect, splitter_rect) = split_rect(rect, *dir, *fraction);
+            ctx.add_paint_cmd(
+                layer,
+                PaintCmd::Rect {
+                    rect: splitter_rect,
+                    corner_radius: 0.0,
+                    fill_color: style.interact_fill_color(&interact),
+                    outline: None,
+                },
+            );
+
+            path.push(false);
+            ui_node(ctx, dock_id, first, first_rect, path, undock);
+            path.pop();
+            path.push(true);
+            ui_node(ctx, dock_id, second, second_rect, path, undock);
+            path.pop();
+        }
+        Node::Tabs { tabs, active } => {
+            let bar_height = tab_bar_height(&style);
+            let bar_rect = Rect::from_min_size(rect.min, vec2(rect.width(), bar_height));
+
+            ctx.add_paint_cmd(
+                layer,
+                PaintCmd::Rect {
+                    rect,
+                    corner_radius: 0.0,
+                    fill_color: Some(style.background_fill_color()),
+                    outline: Some(Outline::new(1.0, color::gray(128, 255))),
+                },
+            );
+
+            let text_style = TextStyle::Button;
+            let font = &ctx.fonts[text_style];
+
+            let mut tab_rects = Vec::with_capacity(tabs.len());
+            let mut x = bar_rect.left();
+            for tab in tabs.iter() {
+                let (_, text_size) = font.layout_single_line(&tab.title);
+                let width = text_size.x + 2.0 * style.button_padding.x;
+                tab_rects.push(Rect::from_min_size(
+                    pos2(x, bar_rect.top()),
+                    vec2(width, bar_height),
+                ));
+                x += width + style.item_spacing.x / 2.0;
+            }
+
+            let mut reorder = None;
+            for (i, tab) in tabs.iter().enumerate() {
+                let interact =
+                    ctx.interact(layer, &bar_rect, &tab_rects[i], Some(dock_id.with(tab.id)));
+                if interact.clicked || (interact.active && ctx.input.mouse_pressed) {
+                    *active = i;
+                }
+                if interact.active {
+                    if let Some(mouse_pos) = ctx.input.mouse_pos {
+                        if (mouse_pos.y - bar_rect.center().y).abs() > bar_height {
+                            // Dragged out of the tab bar
+                            *undock = Some((tab.id, rect.size()));
+                        } else if let Some(target) = tab_rects
+                            .iter()
+                            .position(|r| r.range_x().contains(&mouse_pos.x))
+                        {
+                            if target != i {
+                                reorder = Some((i, target));
+                            }
+                        }
+                    }
+                }
+
+                let is_active = i == *active;
+                let fill_color = if is_active {
+                    Some(style.background_fill_color())
+                } else {
+                    style.interact_fill_color(&interact)
+                };
+                ctx.graphics.lock().layer(layer).push((
+                    bar_rect,
+                    PaintCmd::Rect {
+                        rect: tab_rects[i],
+                        corner_radius: style.interact_corner_radius(&interact),
+                        fill_color,
+                        outline: Some(Outline::new(
+                            style.interact_stroke_width(&interact),
+                            style.interact_stroke_color(&interact),
+                        )),
+                    },
+                ));
+
+                let (text, text_size) = font.layout_single_line(&tab.title);
+                let text_pos =
+                    tab_rects[i].left_center() + vec2(style.button_padding.x, -0.5 * text_size.y);
+                let text_color = style.interact_stroke_color(&interact);
+                for fragment in text {
+                    ctx.graphics.lock().layer(layer).push((
+                        bar_rect,
+                        PaintCmd::Text {
+                            color: text_color,
+                            pos: text_pos + vec2(0.0, fragment.y_offset),
+                            text: fragment.text,
+                            text_style,
+                            x_offsets: fragment.x_offsets,
+                        },
+                    ));
+                }
+            }
+
+            if let Some((from, to)) = reorder {
+                let tab = tabs.remove(from);
+                tabs.insert(to, tab);
+                *active = to;
+            }
+        }
+    }
+}
@@ -7,6 +7,24 @@ use std::{fmt::Debug, hash::Hash, sync::Arc};
 
 use crate::*;
 
+/// Where a floating goes in the stack of floatings.
+/// Within the same `Order`, the one clicked last is on top.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Order {
+    /// Always below other floatings
+    Background,
+    /// The default
+    Middle,
+    /// Always above other floatings, e.g. for an overlay
+    Foreground,
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Order::Middle
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct State {
     /// Last known pos
@@ -14,6 +32,22 @@ pub struct State {
 
     /// Last know size. Used for catching clicks.
     pub size: Vec2,
+
+    pub order: Order,
+}
+
+impl State {
+    /// Keep enough of us within `rect` that we can still be grabbed by the top,
+    /// e.g. by the title bar of a window.
+    pub fn constrain_to(&mut self, rect: Rect) {
+        let margin = 32.0; // TODO: style
+        self.pos = self
+            .pos
+            .max(pos2(rect.left() + margin - self.size.x, rect.top()));
+        self.pos = self
+            .pos
+            .min(pos2(rect.right() - margin, rect.bottom() - margin));
+    }
 }
 
 // TODO: rename Floating to something else. Area?
@@ -21,7 +55,9 @@ pub struct State {
 pub struct Floating {
     id: Id,
     movable: bool,
+    drag_anywhere: bool,
     default_pos: Option<Pos2>,
+    order: Order,
 }
 
 impl Floating {
@@ -29,7 +65,9 @@ impl Floating {
         Self {
             id: Id::new(id_source),
             movable: true,
+            drag_anywhere: true,
             default_pos: None,
+            order: Order::Middle,
         }
     }
 
@@ -38,10 +76,35 @@ impl Floating {
         self
     }
 
+    /// If true (default), you can move it by dragging any part of it
+    /// that isn't used by a widget.
+    /// If false, only a widget inside it interacting with `move_id()` can move it,
+    /// e.g. the title bar of a `Window`.
+    pub fn drag_anywhere(mut self, drag_anywhere: bool) -> Self {
+        self.drag_anywhere = drag_anywhere;
+        self
+    }
+
     pub fn default_pos(mut self, default_pos: Pos2) -> Self {
         self.default_pos = Some(default_pos);
         self
     }
+
+    /// Keep it above or below other floatings.
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Use with e.g. `Memory::move_floating_to_top`.
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Interact with this id to move the `Floating`.
+    pub fn move_id(&self) -> Id {
+        self.id.with("move")
+    }
 }
 
 impl Floating {
@@ -53,13 +116,18 @@ impl Floating {
         let (mut state, _is_new) = match ctx.memory.lock().get_floating(id) {
             Some(state) => (state, false),
             None => {
+                let available_rect = ctx.available_rect();
                 let state = State {
-                    pos: default_pos,
+                    pos: default_pos.max(available_rect.min),
                     size: Vec2::zero(),
+                    order: self.order,
                 };
                 (state, true)
             }
         };
+        state.order = self.order;
+        // The screen may have changed size since last frame:
+        state.constrain_to(ctx.available_rect());
         state.pos = state.pos.round();
 
         let mut region = Region::new(
@@ -73,26 +141,34 @@ impl Floating {
 
         let rect = Rect::from_min_size(state.pos, state.size);
         let clip_rect = Rect::everything();
-        let move_interact = ctx.interact(layer, &clip_rect, &rect, Some(id.with("move")));
+        let move_id = self.move_id();
+        let mut move_interact = if self.drag_anywhere {
+            ctx.interact(layer, &clip_rect, &rect, Some(move_id))
+        } else {
+            ctx.interact(layer, &clip_rect, &rect, None)
+        };
+        move_interact.active = self.movable && ctx.memory.lock().active_id == Some(move_id);
 
         if move_interact.active {
             state.pos += ctx.input().mouse_move;
         }
 
-        // Constrain to screen:
-        let margin = 32.0;
-        state.pos = state.pos.max(pos2(margin - state.size.x, 0.0));
-        state.pos = state.pos.min(pos2(
-            ctx.input.screen_size.x - margin,
-            ctx.input.screen_size.y - margin,
-        ));
+        // Constrain to the part of the screen not covered by panels:
+        state.constrain_to(ctx.available_rect());
 
         state.pos = state.pos.round();
 
         if move_interact.active || mouse_pressed_on_floating(ctx, id) {
             ctx.memory.lock().move_floating_to_top(id);
         }
-        ctx.memory.lock().set_floating_state(id, state);
+
+        let mut memory = ctx.memory.lock();
+        if move_interact.active {
+            memory.dragged_floating = Some(id);
+        } else if memory.dragged_floating == Some(id) {
+            memory.dragged_floating = None;
+        }
+        memory.set_floating_state(id, state);
     }
 }
 
@@ -0,0 +1,444 @@
+//! Menus: a `MenuBar` with dropdown `Menu`s, which contain `MenuItem`s and nested `Menu`s.
+//!
+//! Which menus are open is stored in `Memory`, so only one menu (and its submenus) is open at a time.
+//! A menu opens when you click its title in the bar, and you can then switch menu by hovering the others.
+//! Submenus open on hover. Clicking an item closes all menus.
+//!
+//! Usage:
+//! ```ignore
+//! MenuBar::new().show(region, |region| {
+//!     Menu::new("File").show(region, |region| {
+//!         if region.add(MenuItem::new("Open").shortcut("Ctrl+O")).clicked {
+//!             // ...
+//!         }
+//!         menu::separator(region);
+//!         Menu::new("Recent").show(region, |region| {
+//!             region.add(MenuItem::new("foo.txt"));
+//!         });
+//!     });
+//! });
+//! ```
+
+use crate::{
+    color::*,
+    containers::{Placement, Popup},
+    *,
+};
+
+/// A horizontal bar of menu titles, e.g. at the top of the screen or of a `Window`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MenuBar {}
+
+impl MenuBar {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show(self, region: &mut Region, add_contents: impl FnOnce(&mut Region)) {
+        let where_to_put_background = region.paint_list_len();
+
+        let child_rect = Rect::from_min_max(region.cursor, region.bottom_right());
+        let mut bar_region = Region {
+            dir: Direction::Horizontal,
+            align: Align::Center,
+            ..region.child_region(child_rect)
+        };
+        add_contents(&mut bar_region);
+        let size = vec2(region.available_width(), bar_region.bounding_size().y);
+
+        let interact = region.reserve_space(size, None);
+        region.insert_paint_cmd(
+            where_to_put_background,
+            PaintCmd::Rect {
+                corner_radius: 0.0,
+                fill_color: Some(region.style().background_fill_color()),
+                outline: None,
+                rect: interact.rect,
+            },
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A dropdown menu in a `MenuBar`, or a submenu when shown in another menu.
+#[derive(Clone, Debug)]
+pub struct Menu {
+    title: String,
+}
+
+impl Menu {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+        }
+    }
+
+    pub fn show(self, region: &mut Region, add_contents: impl FnOnce(&mut Region)) {
+        let id = region.make_child_id(&self.title);
+        let (anchor, placement, depth) = match menu_depth(region) {
+            None => (title_ui(region, id, &self.title), Placement::Below, 0),
+            Some(parent_depth) => {
+                let depth = parent_depth + 1;
+                let interact = submenu_ui(region, id, depth, &self.title);
+                (region.response(interact), Placement::Right, depth)
+            }
+        };
+
+        if region.memory().open_menus.get(depth) != Some(&id) {
+            return;
+        }
+
+        region.memory().open_popup(id);
+        let shown = Popup::new(id)
+            .placement(placement)
+            .show(&anchor, add_contents);
+
+        let mut memory = region.memory();
+        if !shown && memory.open_menus.get(depth) == Some(&id) {
+            // Closed by clicking outside or pressing Escape.
+            memory.close_menus_from(depth);
+        }
+        if memory.open_menus.get(depth) != Some(&id) {
+            // Closed by the contents, e.g. by clicking an item.
+            memory.close_popup(id);
+        }
+    }
+}
+
+/// The title of a menu in the menu bar.
+fn title_ui(region: &mut Region, id: Id, title: &str) -> GuiResponse {
+    let text_style = TextStyle::Button;
+    let font = &region.fonts()[text_style];
+    let (text, text_size) = font.layout_single_line(title);
+    let padding = region.style().button_padding;
+    let interact = region.reserve_space(text_size + 2.0 * padding, Some(id));
+
+    {
+        let mut memory = region.memory();
+        let was_open = memory.open_menus.first() == Some(&id);
+        let other_is_open = match memory.open_menus.first() {
+            Some(&open_id) => open_id != id && open_id != Id::context_menu(),
+            None => false,
+        };
+        if interact.clicked || (interact.hovered && other_is_open) {
+            memory.close_menus_from(0);
+            if !was_open {
+                memory.open_menus.push(id);
+            }
+        }
+    }
+
+    let is_open = region.memory().open_menus.first() == Some(&id);
+    let look = InteractInfo {
+        hovered: interact.hovered || is_open,
+        ..interact
+    };
+    if look.hovered {
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: region.style().interact_corner_radius(&look),
+            fill_color: region.style().interact_fill_color(&look),
+            outline: None,
+            rect: interact.rect,
+        });
+    }
+    let text_color = region.style().interact_stroke_color(&look);
+    region.add_text(
+        interact.rect.min + padding,
+        text_style,
+        text,
+        Some(text_color),
+    );
+
+    region.response(interact)
+}
+
+/// The title of a submenu, shown as an item in its parent menu.
+fn submenu_ui(region: &mut Region, id: Id, depth: usize, title: &str) -> InteractInfo {
+    let is_open = region.memory().open_menus.get(depth) == Some(&id);
+    let interact = item_ui(region, Some(id), title, "", None, true, is_open);
+    if interact.hovered && !is_open {
+        let mut memory = region.memory();
+        memory.close_menus_from(depth);
+        memory.open_menus.push(id);
+    }
+    interact
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ContextMenuState {
+    /// The widget that was right-clicked.
+    anchor: Rect,
+
+    /// Where it was right-clicked.
+    pos: Pos2,
+
+    /// When it was right-clicked.
+    time: f64,
+}
+
+/// See `GuiResponse::context_menu`.
+pub(crate) fn context_menu(anchor: &GuiResponse, add_contents: impl FnOnce(&mut Region)) {
+    let ctx = &anchor.ctx;
+    let id = Id::context_menu();
+    let time = ctx.input().time;
+
+    if let (true, Some(mouse_pos)) = (ctx.input().secondary_pressed, ctx.input().mouse_pos) {
+        let mut memory = ctx.memory.lock();
+        // Nested widgets may all have context menus. The first (innermost) one wins:
+        let already_opened = memory.context_menu.map(|state| state.time) == Some(time);
+        if anchor.hovered && !already_opened {
+            memory.close_menus();
+            memory.open_menus.push(id);
+            memory.open_popup(id);
+            memory.context_menu = Some(ContextMenuState {
+                anchor: anchor.rect,
+                pos: mouse_pos,
+                time,
+            });
+        }
+    }
+
+    let pos = {
+        let memory = ctx.memory.lock();
+        match memory.context_menu {
+            Some(state)
+                if state.anchor == anchor.rect && memory.open_menus.first() == Some(&id) =>
+            {
+                state.pos
+            }
+            _ => return,
+        }
+    };
+
+    // Put the popup right at the mouse:
+    let at_mouse = GuiResponse {
+        hovered: anchor.hovered,
+        clicked: anchor.clicked,
+        active: anchor.active,
+        rect: Rect::from_min_size(pos, Vec2::zero()),
+        ctx: ctx.clone(),
+    };
+    ctx.memory.lock().open_popup(id);
+    let shown = Popup::new(id).show(&at_mouse, add_contents);
+
+    let mut memory = ctx.memory.lock();
+    if !shown && memory.open_menus.first() == Some(&id) {
+        memory.close_menus();
+    }
+    if memory.open_menus.first() != Some(&id) {
+        memory.close_popup(id);
+        memory.context_menu = None;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A clickable item in a `Menu`. Clicking it closes all menus.
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    text: String,
+    shortcut: String,
+    checked: Option<bool>,
+    enabled: bool,
+}
+
+impl MenuItem {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            shortcut: String::new(),
+            checked: None,
+            enabled: true,
+        }
+    }
+
+    /// Text shown to the right, e.g. "Ctrl+S".
+    /// This is just a hint: you need to check for the key press yourself.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    /// Show a check mark if `checked`. Toggle the value yourself when the item is clicked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// If false, the item is grayed out and can't be clicked.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl Widget for MenuItem {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let id = region.make_position_id();
+        let interaction_id = if self.enabled { Some(id) } else { None };
+        let interact = item_ui(
+            region,
+            interaction_id,
+            &self.text,
+            &self.shortcut,
+            self.checked,
+            false,
+            false,
+        );
+
+        if interact.hovered {
+            // Close any submenu of a sibling:
+            if let Some(depth) = menu_depth(region) {
+                region.memory().close_menus_from(depth + 1);
+            }
+        }
+        if interact.clicked {
+            region.memory().close_menus();
+        }
+
+        region.response(interact)
+    }
+}
+
+/// A horizontal line between groups of items in a `Menu`.
+/// Use this rather than `Separator`, which would make the menu as wide as it can be.
+pub fn separator(region: &mut Region) {
+    let width = menu_width(region);
+    let height = 2.0 * region.style().item_spacing.y;
+    let interact = region.reserve_space(vec2(width, height), None);
+    let y = interact.rect.center().y;
+    region.add_paint_cmd(PaintCmd::line_segment(
+        (
+            pos2(interact.rect.left(), y),
+            pos2(interact.rect.right(), y),
+        ),
+        gray(150, 255), // TODO: style
+        region.style().line_width,
+    ));
+}
+
+// ----------------------------------------------------------------------------
+
+/// If `region` is the contents of an open menu, where is that menu in `Memory::open_menus`?
+/// The contents of a menu has the same id as the menu.
+fn menu_depth(region: &Region) -> Option<usize> {
+    region
+        .memory()
+        .open_menus
+        .iter()
+        .position(|id| *id == region.id)
+}
+
+/// How wide the contents of the menu in `region` was last frame.
+/// We make all items this wide, so that shortcuts line up and the whole row can be clicked.
+fn menu_width(region: &Region) -> f32 {
+    let rect = match region.memory().popups.get(&region.id) {
+        Some(state) if !state.rect.is_empty() => state.rect,
+        _ => return 0.0,
+    };
+    rect.width() - 2.0 * region.style().window_padding.x
+}
+
+/// A row in a menu: check mark, text, shortcut and submenu arrow.
+fn item_ui(
+    region: &mut Region,
+    interaction_id: Option<Id>,
+    text: &str,
+    shortcut: &str,
+    checked: Option<bool>,
+    has_submenu: bool,
+    highlighted: bool,
+) -> InteractInfo {
+    let text_style = TextStyle::Button;
+    let style = *region.style();
+    let padding = style.button_padding;
+    let icon_width = style.start_icon_width;
+
+    let font = &region.fonts()[text_style];
+    let (text, text_size) = font.layout_single_line(text);
+    let (shortcut, shortcut_size) = font.layout_single_line(shortcut);
+    let shortcut_width = if shortcut_size.x > 0.0 {
+        2.0 * style.item_spacing.x + shortcut_size.x
+    } else {
+        0.0
+    };
+
+    let natural_width =
+        padding.x + icon_width + text_size.x + shortcut_width + icon_width + padding.x;
+    let size = vec2(
+        natural_width.max(menu_width(region)),
+        text_size.y + 2.0 * padding.y,
+    );
+    let interact = region.reserve_space(size, interaction_id);
+    let enabled = interaction_id.is_some();
+    let rect = interact.rect;
+
+    let look = InteractInfo {
+        hovered: (interact.hovered && enabled) || highlighted,
+        ..interact
+    };
+    if look.hovered {
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: 0.0,
+            fill_color: style.interact_fill_color(&look),
+            outline: None,
+            rect,
+        });
+    }
+
+    let text_color = if enabled {
+        style.interact_stroke_color(&look)
+    } else {
+        style.disabled_text_color()
+    };
+    let text_pos = pos2(rect.left() + padding.x + icon_width, rect.top() + padding.y);
+    region.add_text(text_pos, text_style, text, Some(text_color));
+
+    let shortcut_pos = pos2(
+        rect.right() - padding.x - icon_width - shortcut_size.x,
+        text_pos.y,
+    );
+    region.add_text(
+        shortcut_pos,
+        text_style,
+        shortcut,
+        Some(style.disabled_text_color()),
+    );
+
+    if checked == Some(true) {
+        let icon_column = Rect::from_min_max(pos2(rect.left() + padding.x, rect.top()), rect.max);
+        let (small_icon_rect, _) = style.icon_rectangles(&icon_column);
+        region.add_paint_cmd(PaintCmd::Line {
+            points: vec![
+                pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                pos2(small_icon_rect.right(), small_icon_rect.top()),
+            ],
+            color: text_color,
+            width: style.line_width,
+        });
+    }
+
+    if has_submenu {
+        let icon_column = Rect::from_min_max(
+            pos2(rect.right() - padding.x - icon_width, rect.top()),
+            rect.max,
+        );
+        let (small_icon_rect, _) = style.icon_rectangles(&icon_column);
+        let center = small_icon_rect.center();
+        region.add_paint_cmd(PaintCmd::Line {
+            points: vec![
+                pos2(center.x - 2.5, small_icon_rect.top()),
+                pos2(center.x + 2.5, center.y),
+                pos2(center.x - 2.5, small_icon_rect.bottom()),
+            ],
+            color: text_color,
+            width: style.line_width,
+        });
+    }
+
+    interact
+}
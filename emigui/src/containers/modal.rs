@@ -0,0 +1,130 @@
+//! A modal dialog dims everything behind it and takes all the input
+//! until it is closed.
+//!
+//! Modals stack: a modal opened from within another modal is shown on top of it,
+//! and only the top one can be interacted with.
+
+use std::{hash::Hash, sync::Arc};
+
+use crate::{containers::Frame, *};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Size of the dialog last frame. Used for centering it.
+    pub size: Vec2,
+}
+
+#[derive(Debug)]
+pub struct Modal<'open> {
+    id: Id,
+    open: Option<&'open mut bool>,
+    close_on_escape: bool,
+}
+
+impl<'open> Modal<'open> {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            open: None,
+            close_on_escape: true,
+        }
+    }
+
+    /// The modal is only shown while `open` is `true`.
+    /// Needed for `close_on_escape`.
+    pub fn open(mut self, open: &'open mut bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// Set `open` to `false` when the user presses Escape. Default: `true`.
+    /// Escape goes to any open popup, or a widget using it (e.g. to cancel an edit), first.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+}
+
+impl<'open> Modal<'open> {
+    pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
+        let Modal {
+            id,
+            open,
+            close_on_escape,
+        } = self;
+
+        if let Some(open) = &open {
+            if !**open {
+                return;
+            }
+        }
+
+        let screen_rect = Rect::from_min_size(Pos2::default(), ctx.input().screen_size);
+        let id = ctx.register_unique_id(id, "Modal", screen_rect.center());
+        let layer = Layer::Modal(id);
+
+        let (state, is_top, escape_is_for_us) = {
+            let mut memory = ctx.memory.lock();
+            if !memory.modal_stack.contains(&id) {
+                // Just opened. Whatever was focused or popped up is behind us now:
+                memory.kb_focus_id = None;
+                memory.popups.clear();
+                memory.tooltip = None;
+            }
+            memory.modals_this_frame.push(id);
+            let is_top = memory.modal_stack.last() == Some(&id);
+            // Escape first closes popups (menus, combo boxes, ...):
+            let escape_is_for_us = memory.popups.is_empty();
+            (
+                memory.modals.get(&id).cloned().unwrap_or_default(),
+                is_top,
+                escape_is_for_us,
+            )
+        };
+
+        ctx.add_paint_cmd(
+            layer,
+            PaintCmd::Rect {
+                rect: screen_rect,
+                corner_radius: 0.0,
+                fill_color: Some(color::srgba(0, 0, 0, 128)), // TODO: style
+                outline: None,
+            },
+        );
+
+        let pos = ctx.round_pos_to_pixels(screen_rect.center() - state.size / 2.0);
+        let pos = pos.max(Pos2::default());
+        let mut region = Region::new(
+            ctx.clone(),
+            layer,
+            id,
+            Rect::from_min_size(pos, Vec2::infinity()),
+        );
+        Frame::default().show(&mut region, add_contents);
+
+        let size = region.bounding_size().ceil();
+        let used_escape = {
+            let mut memory = ctx.memory.lock();
+            memory.modals.insert(id, State { size });
+            memory.used_escape
+        };
+
+        // After the contents, so we know if a widget in them used the Escape:
+        if close_on_escape && is_top && escape_is_for_us && !used_escape && pressed_escape(ctx) {
+            if let Some(open) = open {
+                *open = false;
+                ctx.request_repaint(); // To get rid of us
+            }
+        }
+    }
+}
+
+fn pressed_escape(ctx: &Context) -> bool {
+    ctx.input().events.iter().any(|event| {
+        *event
+            == Event::Key {
+                key: Key::Escape,
+                pressed: true,
+            }
+    })
+}
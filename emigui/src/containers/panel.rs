@@ -0,0 +1,308 @@
+//! Panels are docked to the edges of the screen and live in the background layer.
+//! Each panel takes a slice of `Context::available_rect()`,
+//! so panels placed later (and windows) stay out of its way.
+//!
+//! Show side, top and bottom panels first, then the `CentralPanel`, then any windows.
+
+use std::{hash::Hash, ops::RangeInclusive, sync::Arc};
+
+use crate::{containers::resize, *};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+// ----------------------------------------------------------------------------
+
+/// A panel docked to the left or right side of the screen.
+/// The width is remembered in `Memory` and can be changed by dragging the inner edge.
+#[derive(Clone, Debug)]
+pub struct SidePanel {
+    id: Id,
+    side: Side,
+    default_width: f32,
+    width_range: RangeInclusive<f32>,
+    resizable: bool,
+}
+
+impl SidePanel {
+    pub fn left(id_source: impl Hash, default_width: f32) -> Self {
+        Self::new(id_source, Side::Left, default_width)
+    }
+
+    pub fn right(id_source: impl Hash, default_width: f32) -> Self {
+        Self::new(id_source, Side::Right, default_width)
+    }
+
+    fn new(id_source: impl Hash, side: Side, default_width: f32) -> Self {
+        Self {
+            id: Id::new(id_source),
+            side,
+            default_width,
+            width_range: 32.0..=f32::INFINITY,
+            resizable: true,
+        }
+    }
+
+    /// Limit how narrow or wide the user can make the panel.
+    pub fn width_range(mut self, width_range: RangeInclusive<f32>) -> Self {
+        self.width_range = width_range;
+        self
+    }
+
+    /// Can you resize it with the mouse?
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+impl SidePanel {
+    pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
+        let available_rect = ctx.available_rect();
+        let id = ctx.register_unique_id(self.id, "SidePanel", available_rect.min);
+
+        let mut width = match ctx.memory.lock().resize.get(&id) {
+            Some(state) => state.size.x,
+            None => self.default_width,
+        };
+        // Never take up more than what is left of the screen:
+        let max_width = self.width_range.end().min(available_rect.width());
+        width = clamp(width, self.width_range.start().min(max_width)..=max_width);
+
+        // The edge facing the rest of the screen:
+        let edge_x = match self.side {
+            Side::Right => available_rect.right() - width,
+            _ => available_rect.left() + width,
+        };
+
+        if self.resizable {
+            let grab_radius = ctx.style().resize_grab_radius;
+            let handle_rect = Rect::from_min_max(
+                pos2(edge_x - grab_radius, available_rect.top()),
+                pos2(edge_x + grab_radius, available_rect.bottom()),
+            );
+            let interact = ctx.interact(
+                Layer::Background,
+                &Rect::everything(),
+                &handle_rect,
+                Some(id.with("resize")),
+            );
+
+            if interact.active {
+                if let Some(mouse_pos) = ctx.input().mouse_pos {
+                    width = match self.side {
+                        Side::Right => available_rect.right() - mouse_pos.x,
+                        _ => mouse_pos.x - available_rect.left(),
+                    };
+                    width = clamp(width, self.width_range.start().min(max_width)..=max_width);
+                }
+            }
+            if interact.hovered || interact.active {
+                ctx.output.lock().cursor_icon = CursorIcon::ResizeHorizontal;
+            }
+        }
+
+        width = ctx.round_to_pixel(width);
+        let panel_rect = match self.side {
+            Side::Right => Rect::from_min_max(
+                pos2(available_rect.right() - width, available_rect.top()),
+                available_rect.max,
+            ),
+            _ => Rect::from_min_max(
+                available_rect.min,
+                pos2(available_rect.left() + width, available_rect.bottom()),
+            ),
+        };
+
+        show_panel(ctx, id, panel_rect, false, add_contents);
+        paint_edge(ctx, panel_rect, self.side);
+
+        ctx.memory.lock().resize.insert(
+            id,
+            resize::State {
+                size: panel_rect.size(),
+            },
+        );
+        ctx.allocate_panel(self.side, panel_rect);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A panel along the top of the screen, e.g. for a menu bar.
+/// It is as high as its contents.
+#[derive(Clone, Debug)]
+pub struct TopPanel {
+    id: Id,
+}
+
+impl TopPanel {
+    pub fn top(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+        }
+    }
+}
+
+impl TopPanel {
+    pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
+        show_horizontal_panel(ctx, self.id, Side::Top, add_contents)
+    }
+}
+
+/// A panel along the bottom of the screen, e.g. for a status bar.
+/// It is as high as its contents.
+#[derive(Clone, Debug)]
+pub struct BottomPanel {
+    id: Id,
+}
+
+impl BottomPanel {
+    pub fn bottom(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+        }
+    }
+}
+
+impl BottomPanel {
+    pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
+        show_horizontal_panel(ctx, self.id, Side::Bottom, add_contents)
+    }
+}
+
+fn show_horizontal_panel(
+    ctx: &Arc<Context>,
+    id: Id,
+    side: Side,
+    add_contents: impl FnOnce(&mut Region),
+) {
+    let available_rect = ctx.available_rect();
+    let id = ctx.register_unique_id(id, "Panel", available_rect.min);
+
+    // We don't know how high we are until we have laid out the contents,
+    // so we use the height of last frame to decide where a bottom panel starts.
+    let last_height = match ctx.memory.lock().resize.get(&id) {
+        Some(state) => state.size.y,
+        None => 0.0,
+    };
+    let top = match side {
+        Side::Bottom => available_rect.bottom() - last_height,
+        _ => available_rect.top(),
+    };
+    let max_rect = Rect::from_min_max(
+        pos2(available_rect.left(), top),
+        pos2(available_rect.right(), available_rect.bottom()),
+    );
+
+    let where_to_put_contents = ctx.graphics.lock().layer(Layer::Background).len();
+    let height = show_panel(ctx, id, max_rect, true, add_contents).height();
+    if side == Side::Bottom && height != last_height {
+        // The contents were laid out from the wrong top (e.g. on the first frame, when we had no height).
+        // Keep the background (painted first), but hide the contents until next frame:
+        ctx.graphics
+            .lock()
+            .layer(Layer::Background)
+            .truncate(where_to_put_contents + 1);
+        ctx.request_repaint();
+    }
+    let panel_rect = match side {
+        Side::Bottom => Rect::from_min_max(
+            pos2(available_rect.left(), available_rect.bottom() - height),
+            available_rect.max,
+        ),
+        _ => Rect::from_min_size(available_rect.min, vec2(available_rect.width(), height)),
+    };
+    paint_edge(ctx, panel_rect, side);
+
+    ctx.memory.lock().resize.insert(
+        id,
+        resize::State {
+            size: panel_rect.size(),
+        },
+    );
+    ctx.allocate_panel(side, panel_rect);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Takes up whatever space is left after all side, top and bottom panels.
+/// Windows are still shown on top of it.
+#[derive(Clone, Debug, Default)]
+pub struct CentralPanel {}
+
+impl CentralPanel {
+    pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
+        let panel_rect = ctx.available_rect();
+        let id = ctx.register_unique_id(Id::new("central_panel"), "CentralPanel", panel_rect.min);
+        show_panel(ctx, id, panel_rect, false, add_contents);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paint the panel background and the contents.
+/// If `fit_height` is set the panel is as high as its contents, otherwise it fills `max_rect`.
+/// Returns the rect actually used.
+fn show_panel(
+    ctx: &Arc<Context>,
+    id: Id,
+    max_rect: Rect,
+    fit_height: bool,
+    add_contents: impl FnOnce(&mut Region),
+) -> Rect {
+    let layer = Layer::Background;
+    let style = ctx.style();
+    let padding = style.window_padding;
+    let where_to_put_background = ctx.graphics.lock().layer(layer).len();
+
+    let inner_rect = Rect::from_min_max(max_rect.min + padding, max_rect.max - padding);
+    let mut region = Region::new(ctx.clone(), layer, id, inner_rect);
+    region.clip_rect = max_rect;
+    add_contents(&mut region);
+
+    let panel_rect = if fit_height {
+        // TODO: handle the last item_spacing in a nicer way
+        let inner_height = region.bounding_size().y.ceil();
+        Rect::from_min_size(
+            max_rect.min,
+            vec2(max_rect.width(), inner_height + 2.0 * padding.y),
+        )
+    } else {
+        max_rect
+    };
+
+    ctx.graphics.lock().layer(layer).insert(
+        where_to_put_background,
+        (
+            Rect::everything(),
+            PaintCmd::Rect {
+                corner_radius: 0.0,
+                fill_color: Some(style.background_fill_color()),
+                outline: None,
+                rect: panel_rect,
+            },
+        ),
+    );
+
+    panel_rect
+}
+
+/// A thin line separating the panel from the rest of the screen.
+fn paint_edge(ctx: &Context, rect: Rect, side: Side) {
+    let (a, b) = match side {
+        Side::Left => (rect.right_top(), rect.right_bottom()),
+        Side::Right => (rect.left_top(), rect.left_bottom()),
+        Side::Top => (rect.left_bottom(), rect.right_bottom()),
+        Side::Bottom => (rect.left_top(), rect.right_top()),
+    };
+    ctx.add_paint_cmd(
+        Layer::Background,
+        PaintCmd::line_segment((a, b), color::gray(128, 255), ctx.style().line_width),
+    );
+}
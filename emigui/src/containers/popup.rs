@@ -0,0 +1,253 @@
+//! Popups are shown on top of everything else, attached to the widget that opened them,
+//! e.g. the button of a combo box or a menu.
+//!
+//! Whether or not a popup is open is stored in `Memory`.
+//! A popup closes when you click outside of it or press Escape,
+//! or if it is no longer shown (e.g. because the widget it is attached to went away).
+
+use std::sync::Arc;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct State {
+    /// Where we were shown last frame. Used for catching clicks and for placement.
+    pub rect: Rect,
+
+    /// Was this popup shown (or opened) this frame?
+    /// Popups that are not shown are closed.
+    pub shown: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            rect: Rect::nothing(),
+            shown: true,
+        }
+    }
+}
+
+/// Which side of the widget to put the popup on.
+/// If there is no room there, it is put on the opposite side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Placement {
+    Below,
+    Above,
+    Right,
+    Left,
+}
+
+/// A popup attached to a widget.
+///
+/// Usage:
+/// ```ignore
+/// let response = region.add(Button::new("Open"));
+/// let popup_id = region.make_child_id("my_popup");
+/// if response.clicked {
+///     region.memory().toggle_popup(popup_id);
+/// }
+/// Popup::new(popup_id).show(&response, |region| {
+///     region.add_label("Hello!");
+/// });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Popup {
+    id: Id,
+    placement: Placement,
+    min_width: f32,
+    max_width: f32,
+}
+
+impl Popup {
+    pub fn new(id: Id) -> Self {
+        Self {
+            id,
+            placement: Placement::Below,
+            min_width: 0.0,
+            max_width: 350.0, // TODO: style
+        }
+    }
+
+    /// Which side of the widget the popup prefers. Default: `Below`.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// The popup is at least this wide, e.g. as wide as the widget that opened it.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Text wraps at this width. The popup is otherwise as wide as its contents.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Fixed width.
+    pub fn width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self.max_width = width;
+        self
+    }
+}
+
+impl Popup {
+    /// Show the popup next to `anchor`, if it is open.
+    /// Returns `true` if it was shown.
+    pub fn show(self, anchor: &GuiResponse, add_contents: impl FnOnce(&mut Region)) -> bool {
+        let ctx = &anchor.ctx;
+        let Popup {
+            id,
+            placement,
+            min_width,
+            max_width,
+        } = self;
+
+        let last_rect = match ctx.memory.lock().popups.get(&id) {
+            Some(state) => state.rect,
+            None => return false,
+        };
+
+        if should_close(ctx, anchor.rect) {
+            ctx.memory.lock().close_popup(id);
+            return false;
+        }
+
+        let screen_rect = Rect::from_min_size(Pos2::default(), ctx.input().screen_size);
+        let size = if last_rect.is_empty() {
+            Vec2::zero()
+        } else {
+            last_rect.size()
+        };
+        let pos = place(anchor.rect, size, placement, screen_rect);
+
+        let rect = show_at(ctx, id, pos, min_width, max_width, add_contents);
+        ctx.memory
+            .lock()
+            .popups
+            .insert(id, State { rect, shown: true });
+        true
+    }
+}
+
+/// Did the user click (with any button) outside of all popups (and outside the anchor, which toggles us itself),
+/// or press Escape?
+fn should_close(ctx: &Context, anchor_rect: Rect) -> bool {
+    let pressed_escape = ctx.input().events.iter().any(|event| {
+        *event
+            == Event::Key {
+                key: Key::Escape,
+                pressed: true,
+            }
+    });
+    if pressed_escape {
+        return true;
+    }
+
+    if ctx.input().mouse_pressed || ctx.input().secondary_pressed {
+        if let Some(mouse_pos) = ctx.input().mouse_pos {
+            let memory = ctx.memory.lock();
+            let in_any_popup = memory
+                .popups
+                .values()
+                .any(|state| state.rect.contains(mouse_pos));
+            return !in_any_popup && !anchor_rect.contains(mouse_pos);
+        }
+    }
+    false
+}
+
+/// Where to put a popup of the given size next to `anchor`, staying on screen if possible.
+fn place(anchor: Rect, size: Vec2, placement: Placement, screen_rect: Rect) -> Pos2 {
+    let gap = 2.0; // TODO: style
+    let below = anchor.bottom() + gap;
+    let above = anchor.top() - gap - size.y;
+    let right = anchor.right() + gap;
+    let left = anchor.left() - gap - size.x;
+
+    let fits_below = below + size.y <= screen_rect.bottom();
+    let fits_above = above >= screen_rect.top();
+    let fits_right = right + size.x <= screen_rect.right();
+    let fits_left = left >= screen_rect.left();
+
+    let mut pos = match placement {
+        Placement::Below if fits_below || !fits_above => pos2(anchor.left(), below),
+        Placement::Above if fits_above || !fits_below => pos2(anchor.left(), above),
+        Placement::Below => pos2(anchor.left(), above),
+        Placement::Above => pos2(anchor.left(), below),
+        Placement::Right if fits_right || !fits_left => pos2(right, anchor.top()),
+        Placement::Left if fits_left || !fits_right => pos2(left, anchor.top()),
+        Placement::Right => pos2(left, anchor.top()),
+        Placement::Left => pos2(right, anchor.top()),
+    };
+
+    // If we stick out to the side, line up with the other edge of the anchor instead:
+    match placement {
+        Placement::Below | Placement::Above => {
+            if pos.x + size.x > screen_rect.right() {
+                pos.x = anchor.right() - size.x;
+            }
+        }
+        Placement::Right | Placement::Left => {
+            if pos.y + size.y > screen_rect.bottom() {
+                pos.y = anchor.bottom() - size.y;
+            }
+        }
+    }
+
+    // Stay on screen, if at all possible:
+    pos = pos.min(screen_rect.max - size);
+    pos.max(screen_rect.min)
+}
+
+/// Show a popup with its top left corner at `pos`, and return where we ended up.
+pub(crate) fn show_at(
+    ctx: &Arc<Context>,
+    id: Id,
+    pos: Pos2,
+    min_width: f32,
+    max_width: f32,
+    add_contents: impl FnOnce(&mut Region),
+) -> Rect {
+    let layer = Layer::Popup;
+    let where_to_put_background = ctx.graphics.lock().layer(layer).len();
+
+    let style = ctx.style();
+    let window_padding = style.window_padding;
+    let pos = ctx.round_pos_to_pixels(pos);
+
+    let max_width = max_width.min(ctx.input().screen_size.x - 2.0 * window_padding.x);
+    let inner_rect = Rect::from_min_size(pos + window_padding, vec2(max_width, f32::INFINITY));
+    let mut contents_region = Region::new(ctx.clone(), layer, id, inner_rect);
+
+    add_contents(&mut contents_region);
+
+    // Now insert popup background:
+
+    // TODO: handle the last item_spacing in a nicer way
+    let mut inner_size = contents_region.bounding_size() - style.item_spacing;
+    inner_size.x = inner_size.x.max(min_width - 2.0 * window_padding.x);
+    let outer_size = (inner_size + 2.0 * window_padding).ceil();
+
+    let rect = Rect::from_min_size(pos, outer_size);
+
+    let mut graphics = ctx.graphics.lock();
+    graphics.layer(layer).insert(
+        where_to_put_background,
+        (
+            Rect::everything(),
+            PaintCmd::Rect {
+                corner_radius: 5.0,
+                fill_color: Some(style.background_fill_color()),
+                outline: Some(Outline::new(1.0, color::WHITE)),
+                rect,
+            },
+        ),
+    );
+
+    rect
+}
@@ -9,12 +9,19 @@ pub struct State {
 // TODO: auto-shink/grow should be part of another container!
 #[derive(Clone, Copy, Debug)]
 pub struct Resize {
+    /// If set, we use this id instead of one based on the parent region.
+    pub(crate) id: Option<Id>,
+
     /// If false, we are no enabled
-    resizable: bool,
+    pub(crate) resizable: bool,
+
+    /// Show and handle the resize corner.
+    /// False when someone else (e.g. a `Window` resizable from its edges) does the resizing.
+    pub(crate) show_handle: bool,
 
     // Will still try to stay within parent region bounds
-    min_size: Vec2,
-    max_size: Vec2,
+    pub(crate) min_size: Vec2,
+    pub(crate) max_size: Vec2,
 
     default_size: Vec2,
 
@@ -32,7 +39,9 @@ pub struct Resize {
 impl Default for Resize {
     fn default() -> Self {
         Self {
+            id: None,
             resizable: true,
+            show_handle: true,
             min_size: Vec2::splat(32.0),
             max_size: Vec2::infinity(),
             default_size: vec2(f32::INFINITY, 200.0), // TODO
@@ -136,7 +145,7 @@ impl Resize {
             return add_contents(region);
         }
 
-        let id = region.make_child_id("scroll");
+        let id = self.id.unwrap_or_else(|| region.make_child_id("scroll"));
         self.min_size = self.min_size.min(region.available_space());
         self.max_size = self.max_size.min(region.available_space());
         self.max_size = self.max_size.max(self.min_size);
@@ -160,7 +169,11 @@ impl Resize {
             position + state.size + self.handle_offset - corner_size,
             corner_size,
         );
-        let corner_interact = region.interact_rect(&corner_rect, id.with("corner"));
+        let corner_interact = if self.show_handle {
+            region.interact_rect(&corner_rect, id.with("corner"))
+        } else {
+            InteractInfo::default()
+        };
 
         if corner_interact.active {
             if let Some(mouse_pos) = region.input().mouse_pos {
@@ -218,7 +231,9 @@ impl Resize {
 
         // ------------------------------
 
-        paint_resize_corner(region, &corner_rect, &corner_interact);
+        if self.show_handle {
+            paint_resize_corner(region, &corner_rect, &corner_interact);
+        }
 
         if corner_interact.hovered || corner_interact.active {
             region.ctx().output.lock().cursor_icon = CursorIcon::ResizeNwSe;
@@ -1,31 +1,97 @@
 use crate::*;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 pub struct State {
     /// Positive offset means scrolling down/right
     pub offset: Vec2,
 
-    pub show_scroll: bool, // TODO: default value?
+    /// Were the horizontal and vertical scroll bars shown last frame?
+    pub show_scroll: [bool; 2], // TODO: default value?
+
+    /// Was the user scrolled all the way to the right/bottom last frame?
+    pub at_end: [bool; 2],
+
+    /// Momentum from dragging the contents, in points per second.
+    pub vel: Vec2,
+
+    /// How much more to scroll, smoothly, from mouse wheel line deltas.
+    pub pending_scroll: Vec2,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::default(),
+            show_scroll: [false; 2],
+            // There is no content yet, so we are at the end of it:
+            at_end: [true; 2],
+            vel: Vec2::default(),
+            pending_scroll: Vec2::default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct ScrollArea {
+    id_source: Option<Id>,
+    /// Can we scroll horizontally and vertically, respectively?
+    has_bar: [bool; 2],
+    max_width: f32,
     max_height: f32,
     always_show_scroll: bool,
     auto_hide_scroll: bool,
+    offset: Option<Vec2>,
+    stick_to_end: [bool; 2],
+    drag_to_scroll: bool,
 }
 
 impl Default for ScrollArea {
     fn default() -> Self {
+        Self::vertical()
+    }
+}
+
+impl ScrollArea {
+    /// Scroll vertically only. This is the default.
+    pub fn vertical() -> Self {
+        Self::new([false, true])
+    }
+
+    /// Scroll horizontally only.
+    pub fn horizontal() -> Self {
+        Self::new([true, false])
+    }
+
+    /// Scroll both horizontally and vertically.
+    pub fn both() -> Self {
+        Self::new([true, true])
+    }
+
+    fn new(has_bar: [bool; 2]) -> Self {
         Self {
+            id_source: None,
+            has_bar,
+            max_width: f32::INFINITY,
             max_height: 200.0,
             always_show_scroll: false,
             auto_hide_scroll: true,
+            offset: None,
+            stick_to_end: [false; 2],
+            drag_to_scroll: false,
         }
     }
-}
 
-impl ScrollArea {
+    /// Needed if you have several scroll areas in the same region.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
     pub fn max_height(mut self, max_height: f32) -> Self {
         self.max_height = max_height;
         self
@@ -40,13 +106,46 @@ impl ScrollArea {
         self.auto_hide_scroll = auto_hide_scroll;
         self
     }
+
+    /// Set the scroll offset this frame, overriding what the user has done.
+    /// Positive offset means scrolling down/right.
+    pub fn scroll_offset(mut self, offset: Vec2) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Can you scroll by dragging the contents, e.g. on a touch screen?
+    /// The contents keep scrolling for a while after you let go.
+    /// Default: `false`.
+    pub fn drag_to_scroll(mut self, drag_to_scroll: bool) -> Self {
+        self.drag_to_scroll = drag_to_scroll;
+        self
+    }
+
+    /// Keep scrolled to the right as the contents grow,
+    /// as long as the user hasn't scrolled away from the right edge.
+    pub fn stick_to_right(mut self, stick: bool) -> Self {
+        self.stick_to_end[0] = stick;
+        self
+    }
+
+    /// Keep scrolled to the bottom as the contents grow (e.g. for a log),
+    /// as long as the user hasn't scrolled away from the bottom.
+    pub fn stick_to_bottom(mut self, stick: bool) -> Self {
+        self.stick_to_end[1] = stick;
+        self
+    }
 }
 
 impl ScrollArea {
     pub fn show(self, outer_region: &mut Region, add_contents: impl FnOnce(&mut Region)) {
         let ctx = outer_region.ctx().clone();
+        let has_bar = self.has_bar;
 
-        let scroll_area_id = outer_region.id.with("scroll_area");
+        let scroll_area_id = match self.id_source {
+            Some(id_source) => outer_region.id.with(id_source),
+            None => outer_region.id.with("scroll_area"),
+        };
         let mut state = ctx
             .memory
             .lock()
@@ -54,6 +153,9 @@ impl ScrollArea {
             .get(&scroll_area_id)
             .cloned()
             .unwrap_or_default();
+        if let Some(offset) = self.offset {
+            state.offset = offset;
+        }
 
         // content: size of contents (generally large)
         // outer: size of scroll area including scroll bar(s)
@@ -62,67 +164,197 @@ impl ScrollArea {
         let scroll_bar_width = 16.0;
 
         let outer_size = vec2(
-            outer_region.available_width(),
+            outer_region.available_width().min(self.max_width),
             outer_region.available_height().min(self.max_height),
         );
         let outer_rect = Rect::from_min_size(outer_region.cursor, outer_size);
 
-        let inner_size = if state.show_scroll || !self.auto_hide_scroll {
-            outer_size - vec2(scroll_bar_width, 0.0) // TODO: animate?
-        } else {
-            outer_size
-        };
+        // Leave room for the scroll bars we showed last frame.
+        // The horizontal bar goes below the contents, the vertical one to the right.
+        let mut show_bar = [false; 2];
+        let mut inner_size = outer_size;
+        for d in 0..2 {
+            show_bar[d] = has_bar[d] && (state.show_scroll[d] || !self.auto_hide_scroll);
+            if show_bar[d] {
+                inner_size[1 - d] -= scroll_bar_width; // TODO: animate?
+            }
+        }
         let inner_rect = Rect::from_min_size(outer_region.cursor, inner_size);
 
+        let mut content_max_size = inner_size;
+        for d in 0..2 {
+            if has_bar[d] {
+                content_max_size[d] = f32::INFINITY;
+            }
+        }
         let mut content_region = outer_region.child_region(Rect::from_min_size(
             outer_region.cursor() - state.offset,
-            vec2(inner_size.x, f32::INFINITY),
+            content_max_size,
         ));
         content_region.clip_rect = outer_region.clip_rect().intersect(&inner_rect);
         add_contents(&mut content_region);
         let content_size = content_region.bounding_size();
 
-        let content_interact = outer_region.interact_rect(&inner_rect, scroll_area_id.with("area"));
-        if content_interact.active {
-            // Dragging scroll area to scroll:
-            state.offset.y -= ctx.input.mouse_move.y;
+        for d in 0..2 {
+            if has_bar[d] && self.stick_to_end[d] && state.at_end[d] {
+                state.offset[d] = content_size[d] - inner_size[d];
+            }
+        }
+
+        if has_bar[0] || has_bar[1] {
+            // Only the innermost scroll area containing the target gets to scroll to it.
+            // Targets set after this scroll area last frame are still waiting for us:
+            let target = [&ctx.scroll_target, &ctx.last_scroll_target]
+                .iter()
+                .find_map(|scroll_target| {
+                    let mut scroll_target = scroll_target.lock();
+                    match *scroll_target {
+                        Some((rect, _)) if content_region.child_bounds.contains(rect.center()) => {
+                            scroll_target.take()
+                        }
+                        _ => None,
+                    }
+                });
+            if let Some((rect, align)) = target {
+                let content_min = content_region.desired_rect.min;
+                for d in 0..2 {
+                    if has_bar[d] {
+                        state.offset[d] = match align {
+                            Align::Min => rect.min[d] - content_min[d],
+                            Align::Center => {
+                                rect.center()[d] - content_min[d] - inner_size[d] / 2.0
+                            }
+                            Align::Max => rect.max[d] - content_min[d] - inner_size[d],
+                        };
+                    }
+                }
+            }
+        }
+
+        // Avoid jumps after a long pause between frames:
+        let dt = ctx.input.dt.min(0.1);
+
+        if self.drag_to_scroll {
+            let content_interact =
+                outer_region.interact_rect(&inner_rect, scroll_area_id.with("area"));
+            if content_interact.active {
+                // Dragging scroll area to scroll:
+                let mut mouse_move = ctx.input.mouse_move;
+                for d in 0..2 {
+                    if !has_bar[d] {
+                        mouse_move[d] = 0.0;
+                    }
+                }
+                state.offset -= mouse_move;
+                // Remember how fast we were dragged, for the momentum after release:
+                if dt > 0.0 {
+                    state.vel = -mouse_move / dt;
+                }
+            } else if state.vel != Vec2::zero() {
+                let friction = 2000.0 * dt; // TODO: style
+                let speed = state.vel.length();
+                state.vel *= (speed - friction).max(0.0) / speed;
+                state.offset += state.vel * dt;
+            }
+        }
+
+        if ctx.input.mouse_pressed && outer_region.contains_mouse(&outer_rect) {
+            // Grabbing the area stops any ongoing scrolling:
+            state.vel = Vec2::zero();
+            state.pending_scroll = Vec2::zero();
         }
 
         // TODO: check that nothing else is being inteacted with
         if outer_region.contains_mouse(&outer_rect) && ctx.memory.lock().active_id.is_none() {
-            state.offset.y -= ctx.input.scroll_delta.y;
+            let points_per_line = 24.0; // TODO: style
+            let redirect = |mut delta: Vec2| {
+                if ctx.input.modifiers.shift {
+                    // Shift + wheel scrolls sideways:
+                    delta = vec2(delta.y, delta.x);
+                }
+                if !has_bar[1] && delta.x == 0.0 {
+                    // Let a normal mouse wheel scroll a horizontal-only area:
+                    delta.x = delta.y;
+                }
+                delta
+            };
+            let scroll_delta = redirect(ctx.input.scroll_delta);
+            let smooth_delta = redirect(ctx.input.scroll_delta_lines * points_per_line);
+            for d in 0..2 {
+                if has_bar[d] {
+                    state.offset[d] -= scroll_delta[d];
+                    state.pending_scroll[d] -= smooth_delta[d];
+                }
+            }
+            if scroll_delta != Vec2::zero() || smooth_delta != Vec2::zero() {
+                state.vel = Vec2::zero();
+            }
         }
 
-        let show_scroll_this_frame = content_size.y > inner_size.y || self.always_show_scroll;
-        if show_scroll_this_frame || state.show_scroll {
-            let left = inner_rect.right() + 2.0;
-            let right = outer_rect.right();
-            let corner_radius = (right - left) / 2.0;
-            let top = inner_rect.top();
-            let bottom = inner_rect.bottom();
+        // Animate towards where the mouse wheel wants us to be:
+        if state.pending_scroll != Vec2::zero() {
+            let step = if state.pending_scroll.length() < 1.0 {
+                state.pending_scroll
+            } else {
+                state.pending_scroll * (15.0 * dt).min(1.0)
+            };
+            state.offset += step;
+            state.pending_scroll -= step;
+        }
 
-            let outer_scroll_rect = Rect::from_min_max(
-                pos2(left, inner_rect.top()),
-                pos2(right, inner_rect.bottom()),
-            );
+        if state.vel != Vec2::zero() || state.pending_scroll != Vec2::zero() {
+            // Keep animating even if there is no new input:
+            ctx.request_repaint();
+        }
+
+        let mut show_scroll_this_frame = [false; 2];
+        let mut painted_bar = [false; 2];
+        for d in 0..2 {
+            show_scroll_this_frame[d] =
+                has_bar[d] && (content_size[d] > inner_size[d] || self.always_show_scroll);
+
+            if !show_bar[d] || !(show_scroll_this_frame[d] || state.show_scroll[d]) {
+                continue;
+            }
+            painted_bar[d] = true;
+
+            // d is the axis we scroll along, `other` is the thickness of the bar.
+            let other = 1 - d;
+            let min_main = inner_rect.min[d];
+            let max_main = inner_rect.max[d];
+            let min_cross = inner_rect.max[other] + 2.0;
+            let max_cross = inner_rect.max[other] + scroll_bar_width;
+            let corner_radius = (max_cross - min_cross) / 2.0;
+
+            let bar_rect = |main_min: f32, main_max: f32| {
+                let mut min = Pos2::default();
+                let mut max = Pos2::default();
+                min[d] = main_min;
+                max[d] = main_max;
+                min[other] = min_cross;
+                max[other] = max_cross;
+                Rect::from_min_max(min, max)
+            };
+
+            let outer_scroll_rect = bar_rect(min_main, max_main);
 
             let from_content =
-                |content_y| remap_clamp(content_y, 0.0..=content_size.y, top..=bottom);
+                |content| remap_clamp(content, 0.0..=content_size[d], min_main..=max_main);
 
-            let handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let handle_rect = bar_rect(
+                from_content(state.offset[d]),
+                from_content(state.offset[d] + inner_size[d]),
             );
 
             // intentionally use same id for inside and outside of handle
-            let interact_id = scroll_area_id.with("vertical");
+            let interact_id = scroll_area_id.with(["horizontal", "vertical"][d]);
             let handle_interact = outer_region.interact_rect(&handle_rect, interact_id);
 
             if let Some(mouse_pos) = ctx.input.mouse_pos {
                 if handle_interact.active {
-                    if inner_rect.top() <= mouse_pos.y && mouse_pos.y <= inner_rect.bottom() {
-                        state.offset.y +=
-                            ctx.input.mouse_move.y * content_size.y / inner_rect.height();
+                    if min_main <= mouse_pos[d] && mouse_pos[d] <= max_main {
+                        state.offset[d] +=
+                            ctx.input.mouse_move[d] * content_size[d] / inner_size[d];
                     }
                 } else {
                     // Check for mouse down outside handle:
@@ -131,19 +363,20 @@ impl ScrollArea {
 
                     if scroll_bg_interact.active {
                         // Center scroll at mouse pos:
-                        let mpos_top = mouse_pos.y - handle_rect.height() / 2.0;
-                        state.offset.y = remap(mpos_top, top..=bottom, 0.0..=content_size.y);
+                        let mpos_top = mouse_pos[d] - handle_rect.size()[d] / 2.0;
+                        state.offset[d] =
+                            remap(mpos_top, min_main..=max_main, 0.0..=content_size[d]);
                     }
                 }
             }
 
-            state.offset.y = state.offset.y.max(0.0);
-            state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
+            state.offset[d] = state.offset[d].max(0.0);
+            state.offset[d] = state.offset[d].min(content_size[d] - inner_size[d]);
 
             // Avoid frame-delay by calculating a new handle rect:
-            let handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let handle_rect = bar_rect(
+                from_content(state.offset[d]),
+                from_content(state.offset[d] + inner_size[d]),
             );
 
             let style = outer_region.style();
@@ -165,15 +398,42 @@ impl ScrollArea {
             });
         }
 
-        // let size = content_size.min(inner_rect.size());
-        let size = vec2(
-            content_size.x, // ignore inner_rect, i.e. try to expand horizontally if necessary
-            content_size.y.min(inner_rect.size().y), // respect vertical height.
-        );
+        if painted_bar[0] && painted_bar[1] {
+            // Fill the corner where the two bars meet:
+            let corner_rect = Rect::from_min_max(inner_rect.max + vec2(2.0, 2.0), outer_rect.max);
+            outer_region.add_paint_cmd(PaintCmd::Rect {
+                rect: corner_rect,
+                corner_radius: (scroll_bar_width - 2.0) / 2.0,
+                fill_color: Some(color::gray(0, 196)), // TODO style
+                outline: None,
+            });
+        }
+
+        // Try to expand to fit the contents along axes we don't scroll,
+        // but respect the size of the scroll area along those we do.
+        let mut size = content_size;
+        for d in 0..2 {
+            if has_bar[d] {
+                size[d] = size[d].min(inner_size[d]);
+            }
+            if show_bar[d] {
+                size[1 - d] += scroll_bar_width;
+            }
+        }
         outer_region.reserve_space(size, None);
 
-        state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
-        state.offset.y = state.offset.y.max(0.0);
+        for d in 0..2 {
+            let unclamped = state.offset[d];
+            state.offset[d] = state.offset[d].min(content_size[d] - inner_size[d]);
+            state.offset[d] = state.offset[d].max(0.0);
+            if state.offset[d] != unclamped {
+                // Hit the edge, so stop any momentum or animation:
+                state.vel[d] = 0.0;
+                state.pending_scroll[d] = 0.0;
+            }
+            // Allow for some rounding errors:
+            state.at_end[d] = state.offset[d] >= content_size[d] - inner_size[d] - 1.0;
+        }
         state.show_scroll = show_scroll_this_frame;
 
         outer_region
@@ -183,4 +443,45 @@ impl ScrollArea {
             .scroll_areas
             .insert(scroll_area_id, state);
     }
+
+    /// Efficiently show a large number of rows of the same height.
+    /// `add_contents` is only called for the rows that are visible,
+    /// with the range of row indices to show.
+    /// The full height of all rows is still reserved so the scroll bar is correct.
+    ///
+    /// `row_height` should not include `item_spacing`.
+    pub fn show_rows(
+        self,
+        outer_region: &mut Region,
+        row_height: f32,
+        total_rows: usize,
+        add_contents: impl FnOnce(&mut Region, std::ops::Range<usize>),
+    ) {
+        self.show(outer_region, |region| {
+            let spacing = region.style().item_spacing.y;
+            let row_height_with_spacing = row_height + spacing;
+            let top = region.cursor().y;
+            let visible_rect = region.clip_rect();
+
+            let max_row = (((visible_rect.bottom() - top) / row_height_with_spacing).ceil() + 1.0)
+                .max(0.0) as usize;
+            let max_row = max_row.min(total_rows);
+            let min_row = ((visible_rect.top() - top) / row_height_with_spacing)
+                .floor()
+                .max(0.0) as usize;
+            let min_row = min_row.min(max_row);
+
+            // Skip the rows above the visible part:
+            region.cursor.y += min_row as f32 * row_height_with_spacing;
+
+            add_contents(region, min_row..max_row);
+
+            // Make room for all the rows, not just the visible ones:
+            if total_rows > 0 {
+                let total_height = total_rows as f32 * row_height_with_spacing - spacing;
+                let bottom = pos2(region.desired_rect.left(), top + total_height);
+                region.child_bounds.extend_with(bottom);
+            }
+        })
+    }
 }
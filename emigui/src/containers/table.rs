@@ -0,0 +1,404 @@
+//! A table with a fixed header row and a scrolling body.
+//!
+//! Column widths and the sort order are stored in `Memory`.
+//! Drag the edge of a column header to resize it, click a header to sort by it.
+
+use std::hash::Hash;
+
+use crate::{color::*, containers::ScrollArea, *};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    widths: Vec<f32>,
+    sort: Option<(usize, SortDirection)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    title: String,
+    default_width: f32,
+    min_width: f32,
+    align: Align,
+    sortable: bool,
+}
+
+impl Column {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            default_width: 100.0,
+            min_width: 16.0,
+            align: Align::Min,
+            sortable: true,
+        }
+    }
+
+    pub fn default_width(mut self, default_width: f32) -> Self {
+        self.default_width = default_width;
+        self
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Horizontal alignment of the cells in this column.
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Can you click the header to sort by this column?
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Table {
+    id: Id,
+    columns: Vec<Column>,
+    striped: bool,
+    row_height: Option<f32>,
+    scroll: ScrollArea,
+}
+
+impl Table {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            columns: vec![],
+            striped: true,
+            row_height: None,
+            scroll: ScrollArea::default(),
+        }
+    }
+
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Paint every other row with a slightly different background.
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Height of each row. Default is one line of text.
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// The body is scrolled if it is higher than this. The header is always visible.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.scroll = self.scroll.max_height(max_height);
+        self
+    }
+}
+
+impl Table {
+    pub fn show(self, region: &mut Region, add_rows: impl FnOnce(&mut TableBody<'_>)) {
+        self.show_impl(region, None, |body, _| add_rows(body))
+    }
+
+    /// Efficiently show a large number of rows.
+    /// `add_rows` is only called for the visible rows, with the range of row indices to show.
+    /// Add exactly one `TableBody::row` for each index in the range.
+    pub fn show_rows(
+        self,
+        region: &mut Region,
+        total_rows: usize,
+        add_rows: impl FnOnce(&mut TableBody<'_>, std::ops::Range<usize>),
+    ) {
+        self.show_impl(region, Some(total_rows), add_rows)
+    }
+
+    fn show_impl(
+        self,
+        region: &mut Region,
+        total_rows: Option<usize>,
+        add_rows: impl FnOnce(&mut TableBody<'_>, std::ops::Range<usize>),
+    ) {
+        let Table {
+            id,
+            columns,
+            striped,
+            row_height,
+            scroll,
+        } = self;
+        let id = region.make_child_id(id);
+
+        let mut state = region.memory().tables.get(&id).cloned().unwrap_or_default();
+        state.widths.resize(columns.len(), 0.0);
+        for (width, column) in state.widths.iter_mut().zip(&columns) {
+            if *width <= 0.0 {
+                *width = column.default_width;
+            }
+        }
+
+        let style = *region.style();
+        let row_height = row_height.unwrap_or_else(|| {
+            region.fonts()[TextStyle::Body].line_spacing() + 2.0 * style.button_padding.y
+        });
+        let row_height = region.round_to_pixel(row_height);
+
+        let table_rect = Rect::from_min_max(region.cursor(), region.bottom_right());
+        let mut table_region = Region {
+            id,
+            dir: Direction::Vertical,
+            align: Align::Min,
+            ..region.child_region(table_rect)
+        };
+        // Rows are packed tightly, but the contents of each cell use the normal spacing:
+        let cell_style = style;
+        table_region.style.item_spacing.y = 0.0;
+
+        header_ui(&mut table_region, id, &columns, &mut state);
+
+        let sort = state.sort;
+        let widths = state.widths.clone();
+        region.memory().tables.insert(id, state);
+
+        let add_body = |region: &mut Region, rows: std::ops::Range<usize>| {
+            let mut body = TableBody {
+                region,
+                cell_style,
+                table_id: id,
+                columns: &columns,
+                widths: &widths,
+                row_height,
+                striped,
+                sort,
+                row_index: rows.start,
+            };
+            add_rows(&mut body, rows);
+        };
+        match total_rows {
+            Some(total_rows) => {
+                scroll.show_rows(&mut table_region, row_height, total_rows, add_body)
+            }
+            None => scroll.show(&mut table_region, |region| add_body(region, 0..0)),
+        }
+
+        let size = table_region.bounding_size();
+        region.reserve_space(size, None);
+    }
+}
+
+fn header_ui(region: &mut Region, table_id: Id, columns: &[Column], state: &mut State) {
+    let style = *region.style();
+    let text_style = TextStyle::Button;
+    let line_spacing = region.fonts()[text_style].line_spacing();
+    let header_height = line_spacing + 2.0 * style.button_padding.y;
+    let top_left = region.cursor();
+
+    // Resize first so that dragging an edge never counts as clicking a header:
+    let mut x = top_left.x;
+    for (i, column) in columns.iter().enumerate() {
+        x += state.widths[i];
+        let handle_rect = Rect::from_min_max(
+            pos2(x - style.resize_grab_radius, top_left.y),
+            pos2(x + style.resize_grab_radius, top_left.y + header_height),
+        );
+        let interact = region.interact_rect(&handle_rect, table_id.with(("resize", i)));
+        if interact.active {
+            if let Some(mouse_pos) = region.input().mouse_pos {
+                let left = x - state.widths[i];
+                state.widths[i] = (mouse_pos.x - left).max(column.min_width).round();
+                x = left + state.widths[i];
+            }
+        }
+        if interact.hovered || interact.active {
+            region.output().cursor_icon = CursorIcon::ResizeHorizontal;
+        }
+    }
+
+    let mut x = top_left.x;
+    for (i, column) in columns.iter().enumerate() {
+        let rect = Rect::from_min_size(pos2(x, top_left.y), vec2(state.widths[i], header_height));
+        x += state.widths[i];
+
+        let interact_id = if column.sortable {
+            Some(table_id.with(("header", i)))
+        } else {
+            None
+        };
+        let interact = region
+            .ctx()
+            .interact(region.layer, &region.clip_rect(), &rect, interact_id);
+        if interact.clicked && column.sortable {
+            state.sort = match state.sort {
+                Some((col, SortDirection::Ascending)) if col == i => {
+                    Some((i, SortDirection::Descending))
+                }
+                _ => Some((i, SortDirection::Ascending)),
+            };
+        }
+
+        let mut cell_region = region.child_region(rect);
+        cell_region.clip_rect = region.clip_rect().intersect(&rect);
+        cell_region.add_paint_cmd(PaintCmd::Rect {
+            rect,
+            corner_radius: 0.0,
+            fill_color: style.interact_fill_color(&interact),
+            outline: Some(Outline::new(1.0, gray(128, 255))),
+        });
+
+        let font = &region.fonts()[text_style];
+        let (text, text_size) = font.layout_single_line(&column.title);
+        let arrow_width = if state.sort.map(|(col, _)| col) == Some(i) {
+            style.start_icon_width
+        } else {
+            0.0
+        };
+        let inner = Rect::from_min_max(
+            rect.min + style.button_padding,
+            rect.max - style.button_padding - vec2(arrow_width, 0.0),
+        );
+        let text_x = match column.align {
+            Align::Min => inner.left(),
+            Align::Center => inner.center().x - text_size.x / 2.0,
+            Align::Max => inner.right() - text_size.x,
+        };
+        let text_color = style.interact_stroke_color(&interact);
+        cell_region.add_text(
+            pos2(text_x, inner.top()),
+            text_style,
+            text,
+            Some(text_color),
+        );
+
+        if let Some((_, direction)) = state.sort.filter(|(col, _)| *col == i) {
+            let (small_icon_rect, _) = style.icon_rectangles(&rect);
+            let mut arrow_rect = small_icon_rect;
+            arrow_rect.set_center(pos2(
+                rect.right() - style.button_padding.x - style.start_icon_width / 2.0,
+                rect.center().y,
+            ));
+            arrow_rect = arrow_rect.expand(-2.0);
+            let points = match direction {
+                SortDirection::Ascending => vec![
+                    arrow_rect.left_bottom(),
+                    arrow_rect.center_top(),
+                    arrow_rect.right_bottom(),
+                ],
+                SortDirection::Descending => vec![
+                    arrow_rect.left_top(),
+                    arrow_rect.center_bottom(),
+                    arrow_rect.right_top(),
+                ],
+            };
+            cell_region.add_paint_cmd(PaintCmd::Line {
+                points,
+                color: text_color,
+                width: style.line_width,
+            });
+        }
+    }
+
+    let total_width = x - top_left.x;
+    region.reserve_space(vec2(total_width, header_height), None);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Passed to the closure of `Table::show` to add rows.
+pub struct TableBody<'a> {
+    region: &'a mut Region,
+    cell_style: Style,
+    table_id: Id,
+    columns: &'a [Column],
+    widths: &'a [f32],
+    row_height: f32,
+    striped: bool,
+    sort: Option<(usize, SortDirection)>,
+    row_index: usize,
+}
+
+impl<'a> TableBody<'a> {
+    /// Which column to sort by, and in what direction, as chosen by the user.
+    /// It is up to you to sort the rows accordingly.
+    pub fn sort(&self) -> Option<(usize, SortDirection)> {
+        self.sort
+    }
+
+    /// Add a row. `add_cells` gets one region per column.
+    /// Selected rows are highlighted. The returned response tells you if the row was clicked.
+    pub fn row(&mut self, selected: bool, add_cells: impl FnOnce(&mut [Region])) -> GuiResponse {
+        let style = *self.region.style();
+        let top_left = self.region.cursor();
+        let row_index = self.row_index;
+        self.row_index += 1;
+
+        let total_width: f32 = self.widths.iter().sum();
+        let row_rect = Rect::from_min_size(top_left, vec2(total_width, self.row_height));
+        let where_to_put_background = self.region.paint_list_len();
+
+        let mut x = top_left.x;
+        let mut cells: Vec<Region> = self
+            .columns
+            .iter()
+            .zip(self.widths)
+            .enumerate()
+            .map(|(i, (column, &width))| {
+                let cell_rect =
+                    Rect::from_min_size(pos2(x, top_left.y), vec2(width, self.row_height));
+                x += width;
+                let inner_rect = Rect::from_min_max(
+                    cell_rect.min + style.button_padding,
+                    cell_rect.max - vec2(style.button_padding.x, 0.0),
+                );
+                let clip_rect = self.region.clip_rect().intersect(&cell_rect);
+                Region {
+                    id: self.table_id.with(("cell", row_index, i)),
+                    style: self.cell_style,
+                    dir: Direction::Vertical,
+                    align: column.align,
+                    clip_rect,
+                    ..self.region.child_region(inner_rect)
+                }
+            })
+            .collect();
+        add_cells(&mut cells);
+
+        // Interact after the cells, so that widgets in the cells get the clicks first:
+        let row_id = self.table_id.with(("row", row_index));
+        let interact = self.region.reserve_space(row_rect.size(), Some(row_id));
+
+        let fill_color = if selected {
+            style.interact_fill_color(&InteractInfo {
+                active: true,
+                ..interact
+            })
+        } else if interact.hovered {
+            style.interact_fill_color(&interact)
+        } else if self.striped && row_index % 2 == 1 {
+            Some(srgba(255, 255, 255, 8)) // TODO: style
+        } else {
+            None
+        };
+        if fill_color.is_some() {
+            self.region.insert_paint_cmd(
+                where_to_put_background,
+                PaintCmd::Rect {
+                    rect: interact.rect,
+                    corner_radius: 0.0,
+                    fill_color,
+                    outline: None,
+                },
+            );
+        }
+
+        self.region.response(interact)
+    }
+}
@@ -0,0 +1,123 @@
+//! Tooltips are shown next to the mouse after it has hovered a widget for `Style::tooltip_delay` seconds.
+//!
+//! A tooltip follows the mouse while it is over the widget.
+//! If the mouse leaves the widget towards the tooltip, the tooltip stays put,
+//! so you can move into it and interact with its contents.
+
+use crate::{containers::popup, *};
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct State {
+    /// The widget we are showing a tooltip for.
+    pub anchor: Rect,
+
+    /// When the mouse started hovering `anchor`.
+    pub hover_start: f64,
+
+    /// Where the tooltip is shown.
+    pub pos: Pos2,
+
+    /// Where the mouse last was over `anchor`.
+    pub last_anchor_mouse_pos: Pos2,
+
+    /// Where the tooltip was shown last frame (nothing if not yet shown).
+    /// Used for catching the mouse, and for staying on screen.
+    pub rect: Rect,
+
+    /// Was the tooltip (or its delay) alive this frame?
+    /// Tooltips that are not are forgotten.
+    pub alive: bool,
+}
+
+impl State {
+    /// The mouse can move in here without the tooltip going away.
+    fn keep_alive_rect(&self) -> Rect {
+        let mut rect = self.rect;
+        rect.extend_with(self.last_anchor_mouse_pos);
+        rect
+    }
+}
+
+/// Show a tooltip for `anchor`, if it has been hovered long enough.
+pub(crate) fn show(anchor: &GuiResponse, add_contents: impl FnOnce(&mut Region)) {
+    let ctx = &anchor.ctx;
+    let mouse_pos = match ctx.input().mouse_pos {
+        Some(mouse_pos) => mouse_pos,
+        None => return,
+    };
+    let time = ctx.input().time;
+    let offset = vec2(16.0, 16.0); // TODO: style
+
+    let last_state = ctx
+        .memory
+        .lock()
+        .tooltip
+        .filter(|state| state.anchor == anchor.rect);
+
+    let mut state = if anchor.hovered {
+        let mut state = last_state.unwrap_or(State {
+            anchor: anchor.rect,
+            hover_start: time,
+            pos: mouse_pos,
+            last_anchor_mouse_pos: mouse_pos,
+            rect: Rect::nothing(),
+            alive: true,
+        });
+        state.last_anchor_mouse_pos = mouse_pos;
+        state
+    } else {
+        match last_state {
+            Some(state)
+                if !state.rect.is_empty() && state.keep_alive_rect().contains(mouse_pos) =>
+            {
+                state
+            }
+            _ => return,
+        }
+    };
+    state.alive = true;
+
+    if time - state.hover_start < ctx.style().tooltip_delay as f64 {
+        ctx.memory.lock().tooltip = Some(state);
+        ctx.request_repaint(); // Even if the mouse stays still
+        return;
+    }
+
+    let first_frame = state.rect.is_empty();
+
+    if anchor.hovered {
+        // Follow the mouse, but stay on screen.
+        // Flip to the other side of the mouse rather than covering it:
+        let screen_rect = Rect::from_min_size(Pos2::default(), ctx.input().screen_size);
+        let size = if first_frame {
+            Vec2::zero()
+        } else {
+            state.rect.size()
+        };
+        let mut pos = mouse_pos + offset;
+        if pos.x + size.x > screen_rect.right() {
+            pos.x = mouse_pos.x - offset.x - size.x;
+        }
+        if pos.y + size.y > screen_rect.bottom() {
+            pos.y = mouse_pos.y - offset.y - size.y;
+        }
+        state.pos = pos.min(screen_rect.max - size).max(screen_rect.min);
+    }
+
+    let where_to_discard = ctx.graphics.lock().layer(Layer::Popup).len();
+
+    let max_width = 350.0; // TODO: popup/tooltip width
+    state.rect = popup::show_at(ctx, Id::popup(), state.pos, 0.0, max_width, add_contents);
+
+    if first_frame {
+        // We don't know our size until we have been laid out once,
+        // so don't paint anything yet. Otherwise we would flicker at the edge of the screen.
+        ctx.graphics
+            .lock()
+            .layer(Layer::Popup)
+            .truncate(where_to_discard);
+        ctx.request_repaint(); // So we get painted next frame
+    }
+
+    ctx.memory.lock().tooltip = Some(state);
+}
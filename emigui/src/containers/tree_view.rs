@@ -0,0 +1,432 @@
+//! A tree of nodes that can be expanded, collapsed and selected.
+//!
+//! Which nodes are expanded and selected is stored in `Memory`.
+//! The children of a node are only added while it is expanded, so huge trees are cheap.
+//!
+//! Click a node to select it. With `multi_select`, Ctrl-click toggles a node and Shift-click selects a range.
+//! Once clicked, the tree can be navigated with the arrow keys:
+//! Up/Down move, Right expands (or moves to the first child) and Left collapses (or moves to the parent).
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::{color::*, *};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct State {
+    expanded: HashSet<Id>,
+    selected: HashSet<Id>,
+
+    /// The node moved with the arrow keys. Outlined while the tree has keyboard focus.
+    focused: Option<Id>,
+
+    /// Where a Shift-selection starts.
+    anchor: Option<Id>,
+
+    /// Scroll the focused node into view (after moving it with the keyboard).
+    scroll_to_focused: bool,
+
+    /// The nodes shown last frame, top to bottom.
+    /// Used for keyboard navigation and Shift-clicks, which need to know what is above and below.
+    visible: Vec<VisibleNode>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct VisibleNode {
+    id: Id,
+    parent: Option<Id>,
+    has_children: bool,
+}
+
+impl State {
+    fn index_of(&self, id: Id) -> Option<usize> {
+        self.visible.iter().position(|node| node.id == id)
+    }
+
+    /// Select everything between `anchor` and `id` (as shown last frame), and nothing else.
+    fn select_range(&mut self, id: Id) {
+        let anchor = self.anchor.unwrap_or(id);
+        self.selected.clear();
+        match (self.index_of(anchor), self.index_of(id)) {
+            (Some(a), Some(b)) => {
+                for node in &self.visible[a.min(b)..=a.max(b)] {
+                    self.selected.insert(node.id);
+                }
+            }
+            _ => {
+                self.selected.insert(id);
+            }
+        }
+    }
+
+    fn select_only(&mut self, id: Id) {
+        self.selected.clear();
+        self.selected.insert(id);
+        self.anchor = Some(id);
+    }
+
+    /// Move the focus with the arrow keys.
+    fn keyboard_input(&mut self, events: &[Event], modifiers: Modifiers, multi_select: bool) {
+        for event in events {
+            let key = match event {
+                Event::Key { key, pressed: true } => key,
+                _ => continue,
+            };
+            let index = self.focused.and_then(|id| self.index_of(id));
+            let last = match self.visible.len() {
+                0 => return,
+                len => len - 1,
+            };
+            let new_index = match (key, index) {
+                (Key::Down, None) | (Key::Home, _) => Some(0),
+                (Key::Up, None) | (Key::End, _) => Some(last),
+                (Key::Down, Some(i)) => Some((i + 1).min(last)),
+                (Key::Up, Some(i)) => Some(i.saturating_sub(1)),
+                (Key::Right, Some(i)) => {
+                    let node = self.visible[i];
+                    if node.has_children && !self.expanded.contains(&node.id) {
+                        self.expanded.insert(node.id);
+                        None
+                    } else if i < last && self.visible[i + 1].parent == Some(node.id) {
+                        Some(i + 1) // The first child
+                    } else {
+                        None
+                    }
+                }
+                (Key::Left, Some(i)) => {
+                    let node = self.visible[i];
+                    if self.expanded.remove(&node.id) {
+                        None
+                    } else {
+                        node.parent.and_then(|parent| self.index_of(parent))
+                    }
+                }
+                _ => None,
+            };
+
+            if let Some(new_index) = new_index {
+                let id = self.visible[new_index].id;
+                self.focused = Some(id);
+                self.scroll_to_focused = true;
+                if multi_select && modifiers.shift {
+                    self.select_range(id);
+                } else {
+                    self.select_only(id);
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A tree of expandable and selectable nodes.
+///
+/// Usage:
+/// ```ignore
+/// TreeView::new("scene").multi_select(true).show(region, |tree| {
+///     tree.node("root", "Root", |tree| {
+///         tree.leaf("camera", "Camera");
+///         tree.node("meshes", "Meshes", |tree| {
+///             // Only called while "Meshes" is expanded:
+///             for (i, mesh) in meshes.iter().enumerate() {
+///                 tree.leaf(i, &mesh.name);
+///             }
+///         });
+///     });
+/// });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TreeView {
+    id: Id,
+    multi_select: bool,
+}
+
+impl TreeView {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            multi_select: false,
+        }
+    }
+
+    /// Allow selecting more than one node with Ctrl and Shift.
+    /// Default: false
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+}
+
+impl TreeView {
+    pub fn show(self, region: &mut Region, add_nodes: impl FnOnce(&mut TreeBody<'_>)) {
+        let tree_id = region.make_child_id(self.id);
+        let mut state = region
+            .memory()
+            .tree_views
+            .get(&tree_id)
+            .cloned()
+            .unwrap_or_default();
+
+        if region.has_kb_focus(tree_id) {
+            state.keyboard_input(
+                &region.input().events,
+                region.input().modifiers,
+                self.multi_select,
+            );
+        }
+
+        let mut body = TreeBody {
+            region,
+            tree_id,
+            multi_select: self.multi_select,
+            state,
+            visible: vec![],
+            parent: None,
+            depth: 0,
+            last_sibling_y: None,
+            hovered: false,
+        };
+        add_nodes(&mut body);
+
+        let TreeBody {
+            region,
+            mut state,
+            visible,
+            hovered,
+            ..
+        } = body;
+        // Clicking outside of the tree gives up the keyboard:
+        if region.has_kb_focus(tree_id) && region.input().mouse_pressed && !hovered {
+            region.memory().kb_focus_id = None;
+        }
+        state.visible = visible;
+        region.memory().tree_views.insert(tree_id, state);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Passed to the closure of `TreeView::show` to add nodes.
+pub struct TreeBody<'a> {
+    region: &'a mut Region,
+    tree_id: Id,
+    multi_select: bool,
+    state: State,
+
+    /// The nodes shown so far this frame.
+    visible: Vec<VisibleNode>,
+
+    /// The node whose children we are adding, if any.
+    parent: Option<Id>,
+    depth: usize,
+
+    /// Where the row of the previous node at this depth was, for painting guide lines.
+    last_sibling_y: Option<f32>,
+
+    /// Is the mouse over any of the rows?
+    hovered: bool,
+}
+
+impl<'a> TreeBody<'a> {
+    /// A node which can be expanded to show its children.
+    /// `add_children` is only called while the node is expanded.
+    /// `id_source` must be unique among its siblings.
+    pub fn node(
+        &mut self,
+        id_source: impl Hash,
+        text: impl Into<String>,
+        add_children: impl FnOnce(&mut TreeBody<'a>),
+    ) -> GuiResponse {
+        let id = self.node_id(id_source);
+        let (response, expanded) = self.row(id, text.into(), true);
+
+        if expanded {
+            let outer_parent = self.parent.replace(id);
+            self.depth += 1;
+            self.last_sibling_y = None;
+
+            add_children(self);
+
+            // A guide line from us down to our last child:
+            if let Some(last_child_y) = self.last_sibling_y {
+                let x = self.guide_x(self.depth - 1, &response.rect);
+                self.region.add_paint_cmd(PaintCmd::line_segment(
+                    (pos2(x, response.rect.bottom()), pos2(x, last_child_y)),
+                    guide_color(),
+                    self.region.style().line_width,
+                ));
+            }
+
+            self.depth -= 1;
+            self.parent = outer_parent;
+        }
+
+        self.last_sibling_y = Some(response.rect.center().y);
+        response
+    }
+
+    /// A node without children.
+    /// `id_source` must be unique among its siblings.
+    pub fn leaf(&mut self, id_source: impl Hash, text: impl Into<String>) -> GuiResponse {
+        let id = self.node_id(id_source);
+        let (response, _) = self.row(id, text.into(), false);
+        self.last_sibling_y = Some(response.rect.center().y);
+        response
+    }
+
+    /// Is the child with this `id_source` of the current node selected?
+    pub fn is_selected(&self, id_source: impl Hash) -> bool {
+        self.state.selected.contains(&self.node_id(id_source))
+    }
+
+    fn node_id(&self, id_source: impl Hash) -> Id {
+        self.parent.unwrap_or(self.tree_id).with(id_source)
+    }
+
+    /// The x of the guide line for the children of a node at `depth`.
+    fn guide_x(&self, depth: usize, row_rect: &Rect) -> f32 {
+        let style = self.region.style();
+        row_rect.left() + depth as f32 * style.indent + 0.5 * style.start_icon_width
+    }
+
+    /// Show the row of a node and handle clicks on it.
+    /// Returns whether or not the node is expanded.
+    fn row(&mut self, id: Id, text: String, has_children: bool) -> (GuiResponse, bool) {
+        self.visible.push(VisibleNode {
+            id,
+            parent: self.parent,
+            has_children,
+        });
+
+        let style = *self.region.style();
+        let text_style = TextStyle::Button;
+        let font = &self.region.fonts()[text_style];
+        let (text, text_size) = font.layout_single_line(&text);
+        let row_size = vec2(
+            self.region.available_width(),
+            text_size.y + 2.0 * style.button_padding.y,
+        );
+        let interact = self.region.reserve_space(row_size, Some(id));
+        let rect = interact.rect;
+        let indent = self.depth as f32 * style.indent;
+        let icon_rect = Rect::from_min_size(
+            pos2(rect.left() + indent, rect.top()),
+            vec2(style.start_icon_width, rect.height()),
+        );
+        let (small_icon_rect, _) = style.icon_rectangles(&icon_rect);
+        self.hovered |= interact.hovered;
+
+        if interact.clicked {
+            let on_icon = match self.region.input().mouse_pos {
+                Some(mouse_pos) => icon_rect.contains(mouse_pos),
+                None => false,
+            };
+            if has_children && on_icon {
+                if !self.state.expanded.remove(&id) {
+                    self.state.expanded.insert(id);
+                }
+            } else {
+                self.click(id);
+            }
+            self.state.focused = Some(id);
+            self.region.request_kb_focus(self.tree_id);
+        }
+
+        let selected = self.state.selected.contains(&id);
+        let expanded = has_children && self.state.expanded.contains(&id);
+        let focused = self.state.focused == Some(id) && self.region.has_kb_focus(self.tree_id);
+
+        if selected || interact.hovered {
+            let look = InteractInfo {
+                active: interact.active || selected,
+                ..interact
+            };
+            self.region.add_paint_cmd(PaintCmd::Rect {
+                corner_radius: 0.0,
+                fill_color: style.interact_fill_color(&look),
+                outline: None,
+                rect,
+            });
+        }
+        if focused {
+            self.region.add_paint_cmd(PaintCmd::Rect {
+                corner_radius: 0.0,
+                fill_color: None,
+                outline: Some(Outline::new(style.line_width, style.text_color())),
+                rect,
+            });
+        }
+
+        // A short line from the guide line of our parent:
+        if self.depth > 0 {
+            let parent_x = self.guide_x(self.depth - 1, &rect);
+            self.region.add_paint_cmd(PaintCmd::line_segment(
+                (
+                    pos2(parent_x, rect.center().y),
+                    pos2(small_icon_rect.left(), rect.center().y),
+                ),
+                guide_color(),
+                style.line_width,
+            ));
+        }
+
+        let stroke_color = style.interact_stroke_color(&interact);
+        if has_children {
+            // A triangle pointing right when collapsed, and down when expanded:
+            let points = if expanded {
+                vec![
+                    small_icon_rect.left_top(),
+                    small_icon_rect.right_top(),
+                    pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                ]
+            } else {
+                vec![
+                    small_icon_rect.left_top(),
+                    small_icon_rect.left_bottom(),
+                    pos2(small_icon_rect.right(), small_icon_rect.center().y),
+                ]
+            };
+            let center = small_icon_rect.center();
+            let mut path = mesher::Path::default();
+            for point in points {
+                path.add_point(point, (point - center).normalized());
+            }
+            self.region.add_paint_cmd(PaintCmd::Path {
+                path,
+                closed: true,
+                fill_color: Some(stroke_color),
+                outline: None,
+            });
+        }
+
+        let text_pos = pos2(icon_rect.right(), rect.center().y - 0.5 * text_size.y);
+        self.region
+            .add_text(text_pos, text_style, text, Some(stroke_color));
+
+        let mut response = self.region.response(interact);
+        if focused && self.state.scroll_to_focused {
+            response.scroll_to_me(Align::Center);
+            self.state.scroll_to_focused = false;
+        }
+        (response, expanded)
+    }
+
+    fn click(&mut self, id: Id) {
+        let modifiers = self.region.input().modifiers;
+        if self.multi_select && modifiers.ctrl {
+            if !self.state.selected.remove(&id) {
+                self.state.selected.insert(id);
+            }
+            self.state.anchor = Some(id);
+        } else if self.multi_select && modifiers.shift {
+            self.state.select_range(id);
+        } else {
+            self.state.select_only(id);
+        }
+    }
+}
+
+fn guide_color() -> Color {
+    gray(100, 255) // TODO: style
+}
@@ -1,25 +1,27 @@
 use std::sync::Arc;
 
-use crate::{widgets::*, *};
+use crate::*;
 
 use super::*;
 
 /// A wrapper around other containers for things you often want in a window
-#[derive(Clone, Debug)]
-pub struct Window {
+#[derive(Debug)]
+pub struct Window<'open> {
     pub title: String,
     pub floating: Floating,
     pub frame: Frame,
     pub resize: Resize,
     pub scroll: ScrollArea,
+    open: Option<&'open mut bool>,
+    collapsible: bool,
 }
 
-impl Window {
+impl<'open> Window<'open> {
     pub fn new(title: impl Into<String>) -> Self {
         let title = title.into();
         Self {
             title: title.clone(),
-            floating: Floating::new(title),
+            floating: Floating::new(title).drag_anywhere(false),
             frame: Frame::default(),
             resize: Resize::default()
                 .handle_offset(Vec2::splat(4.0))
@@ -30,9 +32,24 @@ impl Window {
             scroll: ScrollArea::default()
                 .always_show_scroll(false)
                 .max_height(f32::INFINITY), // As large as we can be
+            open: None,
+            collapsible: true,
         }
     }
 
+    /// Show a close button in the title bar, which sets `open` to `false`.
+    /// The window is not shown while `open` is `false`.
+    pub fn open(mut self, open: &'open mut bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// Can you collapse the window to just its title bar?
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
     /// This is quite a crap idea
     /// Usage: `Winmdow::new(...).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     pub fn mutate(mut self, mutate: impl Fn(&mut Self)) -> Self {
@@ -47,6 +64,17 @@ impl Window {
         self
     }
 
+    /// Use with e.g. `Memory::move_floating_to_top`.
+    pub fn id(&self) -> Id {
+        self.floating.id()
+    }
+
+    /// Keep the window above or below other windows.
+    pub fn order(mut self, order: floating::Order) -> Self {
+        self.floating = self.floating.order(order);
+        self
+    }
+
     pub fn default_pos(mut self, default_pos: Pos2) -> Self {
         self.floating = self.floating.default_pos(default_pos);
         self
@@ -80,24 +108,347 @@ impl Window {
     }
 }
 
-impl Window {
+impl<'open> Window<'open> {
     pub fn show(self, ctx: &Arc<Context>, add_contents: impl FnOnce(&mut Region)) {
         let Window {
             title,
             floating,
             frame,
-            resize,
+            mut resize,
             scroll,
+            open,
+            collapsible,
         } = self;
+
+        let window_id = floating.id();
+        if let Some(open) = &open {
+            if !**open {
+                dock::close_window(ctx, window_id);
+                return;
+            }
+        }
+
+        let docked = ctx.memory.lock().docked_windows.get(&window_id).cloned();
+        if let Some(docked) = docked {
+            dock::update_title(ctx, docked.dock_id, window_id, &title);
+
+            // Docked windows are shown in their tab, without frame or title:
+            if let Some(rect) = docked.rect {
+                let id = ctx.register_unique_id(window_id, "Window", rect.min);
+                let padding = ctx.style().window_padding;
+                let inner_rect = Rect::from_min_max(rect.min + padding, rect.max - padding);
+                let mut region = Region::new(ctx.clone(), Layer::Background, id, inner_rect);
+                region.clip_rect = rect;
+                scroll.show(&mut region, add_contents);
+            }
+            return;
+        }
+
+        let collapsing_id = window_id.with("collapsing");
+        let mut collapsing = ctx
+            .memory
+            .lock()
+            .collapsing_headers
+            .get(&collapsing_id)
+            .cloned()
+            .unwrap_or(collapsing_header::State {
+                open: true,
+                ..Default::default()
+            });
+        let move_id = floating.move_id();
+        let mut close_clicked = false;
+
+        let resize_id = window_id.with("resize");
+        resize.id = Some(resize_id);
+        let resize_edges = resize.resizable && collapsing.open;
+        let (min_size, max_size) = (resize.min_size, resize.max_size);
+        // We resize from all edges and corners ourselves:
+        resize.show_handle = !resize_edges;
+
+        // Before the contents, so that we get the mouse press before anything inside the window
+        // (e.g. a scroll bar right at the edge):
+        if resize_edges {
+            resize_from_edges(ctx, window_id, resize_id, min_size..=max_size);
+        }
+
         // TODO: easier way to compose these
         floating.show(ctx, |region| {
             frame.show(region, |region| {
-                resize.show(region, |region| {
-                    region.add(Label::new(title).text_style(TextStyle::Heading));
-                    region.add(Separator::new().line_width(1.0)); // TODO: nicer way to split window title from contents
-                    scroll.show(region, |region| add_contents(region))
-                })
+                let title_bar = TitleBar::new(region, &title, collapsible, open.is_some());
+
+                if collapsing.is_visible(region) {
+                    let body_rect = Rect::from_min_max(region.cursor(), region.bottom_right());
+                    let mut body_region = region.child_region(body_rect);
+                    collapsing.add_contents(&mut body_region, |region| {
+                        resize.show(region, |region| scroll.show(region, add_contents))
+                    });
+                    region.reserve_space(body_region.bounding_size(), None);
+                }
+
+                // After the contents, so we know how wide the window is:
+                close_clicked = title_bar.ui(region, window_id, move_id, &mut collapsing);
             })
-        })
+        });
+
+        ctx.memory
+            .lock()
+            .collapsing_headers
+            .insert(collapsing_id, collapsing);
+        if close_clicked {
+            if let Some(open) = open {
+                *open = false;
+            }
+        }
+
+        dock::drop_window(ctx, window_id, &title);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Drag any of the four edges or four corners of a window to resize it.
+/// Moving the left or top edge also moves the window.
+fn resize_from_edges(
+    ctx: &Arc<Context>,
+    window_id: Id,
+    resize_id: Id,
+    size_range: std::ops::RangeInclusive<Vec2>,
+) {
+    let state = match ctx.memory.lock().get_floating(window_id) {
+        Some(state) => state,
+        None => return,
+    };
+    let rect = Rect::from_min_size(state.pos, state.size);
+    let layer = Layer::Window(window_id);
+    // Only grab inside the window, since that's where its layer catches the mouse:
+    let grab_size = 2.0 * ctx.style().resize_grab_radius;
+
+    // -1 = left/top, 0 = middle, 1 = right/bottom. Corners first, so they win over the edges.
+    let handles = [
+        (-1, -1),
+        (1, -1),
+        (-1, 1),
+        (1, 1),
+        (-1, 0),
+        (1, 0),
+        (0, -1),
+        (0, 1),
+    ];
+    for &(x_side, y_side) in &handles {
+        let side_range = |side: i32, min: f32, max: f32| match side {
+            -1 => (min, min + grab_size),
+            1 => (max - grab_size, max),
+            _ => (min + grab_size, max - grab_size),
+        };
+        let (left, right) = side_range(x_side, rect.left(), rect.right());
+        let (top, bottom) = side_range(y_side, rect.top(), rect.bottom());
+        let handle_rect = Rect::from_min_max(pos2(left, top), pos2(right, bottom));
+
+        let interact = ctx.interact(
+            layer,
+            &Rect::everything(),
+            &handle_rect,
+            Some(window_id.with(("resize_edge", x_side, y_side))),
+        );
+
+        if interact.hovered || interact.active {
+            ctx.output.lock().cursor_icon = match (x_side, y_side) {
+                (0, _) => CursorIcon::ResizeVertical,
+                (_, 0) => CursorIcon::ResizeHorizontal,
+                (x, y) if x == y => CursorIcon::ResizeNwSe,
+                _ => CursorIcon::ResizeNeSw,
+            };
+        }
+
+        if !interact.active {
+            continue;
+        }
+        let mouse_pos = match ctx.input().mouse_pos {
+            Some(mouse_pos) => mouse_pos,
+            None => continue,
+        };
+
+        let mut memory = ctx.memory.lock();
+        let old_size = match memory.resize.get(&resize_id) {
+            Some(resize_state) => resize_state.size,
+            None => continue,
+        };
+
+        // How much do we want to grow?
+        let mut delta = Vec2::zero();
+        match x_side {
+            -1 => delta.x = rect.left() - mouse_pos.x,
+            1 => delta.x = mouse_pos.x - rect.right(),
+            _ => {}
+        }
+        match y_side {
+            -1 => delta.y = rect.top() - mouse_pos.y,
+            1 => delta.y = mouse_pos.y - rect.bottom(),
+            _ => {}
+        }
+        let new_size = (old_size + delta).clamp(size_range.clone());
+        let delta = new_size - old_size;
+
+        let mut state = state;
+        if x_side == -1 {
+            state.pos.x -= delta.x;
+        }
+        if y_side == -1 {
+            state.pos.y -= delta.y;
+        }
+        state.size += delta;
+        memory.set_floating_state(window_id, state);
+        memory
+            .resize
+            .insert(resize_id, resize::State { size: new_size });
+        break;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Collapse button, title and close button.
+/// Dragging the title bar moves the window.
+struct TitleBar {
+    title: Vec<font::TextFragment>,
+    /// The space reserved for the title bar. May get wider in `ui`.
+    rect: Rect,
+    collapsible: bool,
+    closable: bool,
+}
+
+impl TitleBar {
+    fn new(region: &mut Region, title: &str, collapsible: bool, closable: bool) -> Self {
+        let style = *region.style();
+        let font = &region.fonts()[TextStyle::Heading];
+        let (title, text_size) = font.layout_single_line(title);
+        let button_size = text_size.y;
+
+        let mut width = text_size.x;
+        if collapsible {
+            width += style.start_icon_width;
+        }
+        if closable {
+            width += style.item_spacing.x + button_size;
+        }
+        let rect = region.reserve_space(vec2(width, text_size.y), None).rect;
+
+        Self {
+            title,
+            rect,
+            collapsible,
+            closable,
+        }
+    }
+
+    /// Returns true if the close button was clicked.
+    fn ui(
+        self,
+        region: &mut Region,
+        window_id: Id,
+        move_id: Id,
+        collapsing: &mut collapsing_header::State,
+    ) -> bool {
+        let style = *region.style();
+        let rect = Rect::from_min_max(
+            self.rect.min,
+            pos2(region.child_bounds.max.x, self.rect.max.y),
+        );
+        let button_size = rect.height();
+
+        // The buttons interact before the title bar, so they get the clicks:
+        let mut close_clicked = false;
+        if self.closable {
+            let close_rect = Rect::from_min_size(
+                pos2(rect.right() - button_size, rect.top()),
+                Vec2::splat(button_size),
+            );
+            let interact = region.interact_rect(&close_rect, window_id.with("close"));
+            close_clicked = interact.clicked;
+            paint_close_button(region, &interact);
+        }
+
+        let mut text_left = rect.left();
+        if self.collapsible {
+            let icon_rect =
+                Rect::from_min_size(rect.min, vec2(style.start_icon_width, button_size));
+            let interact = region.interact_rect(&icon_rect, window_id.with("collapse"));
+            if interact.clicked {
+                collapsing.toggle(region);
+            }
+            paint_collapse_arrow(region, collapsing.open, &interact);
+            text_left += style.start_icon_width;
+        }
+
+        region.interact_rect(&rect, move_id);
+        region.add_text(
+            pos2(text_left, rect.top()),
+            TextStyle::Heading,
+            self.title,
+            None,
+        );
+
+        if collapsing.is_visible(region) {
+            // Separate the title from the contents:
+            let y = rect.bottom() + style.item_spacing.y / 2.0;
+            region.add_paint_cmd(PaintCmd::line_segment(
+                (pos2(rect.left(), y), pos2(rect.right(), y)),
+                color::gray(128, 255), // TODO: style
+                style.line_width,
+            ));
+        }
+
+        close_clicked
     }
 }
+
+fn paint_close_button(region: &mut Region, interact: &InteractInfo) {
+    let style = region.style();
+    let fill_color = if interact.hovered || interact.active {
+        style.interact_fill_color(interact)
+    } else {
+        None
+    };
+    let stroke_color = style.interact_stroke_color(interact);
+    let stroke_width = style.interact_stroke_width(interact);
+    let corner_radius = style.interact_corner_radius(interact);
+
+    let rect = interact.rect;
+    let cross_rect = rect.expand(-rect.width() / 4.0);
+    region.add_paint_cmd(PaintCmd::Rect {
+        rect,
+        corner_radius,
+        fill_color,
+        outline: None,
+    });
+    region.add_paint_cmd(PaintCmd::line_segment(
+        (cross_rect.left_top(), cross_rect.right_bottom()),
+        stroke_color,
+        stroke_width,
+    ));
+    region.add_paint_cmd(PaintCmd::line_segment(
+        (cross_rect.right_top(), cross_rect.left_bottom()),
+        stroke_color,
+        stroke_width,
+    ));
+}
+
+/// Pointing down when open, right when collapsed.
+fn paint_collapse_arrow(region: &mut Region, open: bool, interact: &InteractInfo) {
+    let style = region.style();
+    let stroke_color = style.interact_stroke_color(interact);
+    let stroke_width = style.interact_stroke_width(interact);
+
+    let (small_icon_rect, _) = style.icon_rectangles(&interact.rect);
+    let rect = small_icon_rect.expand(-1.0);
+    let points = if open {
+        vec![rect.left_top(), rect.center_bottom(), rect.right_top()]
+    } else {
+        vec![rect.left_top(), rect.right_center(), rect.left_bottom()]
+    };
+    region.add_paint_cmd(PaintCmd::Line {
+        points,
+        color: stroke_color,
+        width: stroke_width,
+    });
+}
@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use parking_lot::Mutex;
 
-use crate::{layout::align_rect, *};
+use crate::{containers::panel::Side, layout::align_rect, *};
 
 /// Contains the input, style and output of all GUI commands.
 pub struct Context {
@@ -19,6 +19,16 @@ pub struct Context {
 
     /// Used to debug name clashes of e.g. windows
     used_ids: Mutex<HashMap<Id, Pos2>>,
+
+    /// What is left of the screen after panels have been placed this frame.
+    available_rect: Mutex<Rect>,
+
+    /// Set by `GuiResponse::scroll_to_me`, consumed by the innermost `ScrollArea` containing it.
+    pub(crate) scroll_target: Mutex<Option<(Rect, Align)>>,
+
+    /// A `scroll_target` from last frame that no `ScrollArea` used (yet),
+    /// e.g. because it was set after the `ScrollArea` was shown. Dropped after this frame.
+    pub(crate) last_scroll_target: Mutex<Option<(Rect, Align)>>,
 }
 
 // TODO: remove this impl.
@@ -33,6 +43,9 @@ impl Clone for Context {
             graphics: Mutex::new(self.graphics.lock().clone()),
             output: Mutex::new(self.output.lock().clone()),
             used_ids: Mutex::new(self.used_ids.lock().clone()),
+            available_rect: Mutex::new(*self.available_rect.lock()),
+            scroll_target: Mutex::new(*self.scroll_target.lock()),
+            last_scroll_target: Mutex::new(*self.last_scroll_target.lock()),
         }
     }
 }
@@ -48,6 +61,9 @@ impl Context {
             graphics: Default::default(),
             output: Default::default(),
             used_ids: Default::default(),
+            available_rect: Mutex::new(Rect::nothing()),
+            scroll_target: Default::default(),
+            last_scroll_target: Default::default(),
         }
     }
 
@@ -73,6 +89,10 @@ impl Context {
         &self.input
     }
 
+    pub fn memory(&self) -> parking_lot::MutexGuard<'_, Memory> {
+        self.memory.lock()
+    }
+
     pub fn style(&self) -> Style {
         *self.style.lock()
     }
@@ -84,6 +104,16 @@ impl Context {
     // TODO: move
     pub fn begin_frame(&mut self, gui_input: GuiInput) {
         self.used_ids.lock().clear();
+        self.memory.lock().begin_frame();
+        let screen_resized = gui_input.screen_size != self.input.screen_size;
+        let minimized = gui_input.screen_size.x <= 0.0 || gui_input.screen_size.y <= 0.0;
+        if screen_resized && !minimized {
+            let screen_rect = Rect::from_min_size(Pos2::default(), gui_input.screen_size);
+            self.memory.lock().constrain_floatings_to(screen_rect);
+        }
+        *self.available_rect.lock() = Rect::from_min_size(Pos2::default(), gui_input.screen_size);
+        let unused_scroll_target = self.scroll_target.lock().take();
+        *self.last_scroll_target.lock() = unused_scroll_target;
         self.input = gui_input;
     }
 
@@ -93,7 +123,33 @@ impl Context {
 
     pub fn drain_paint_lists(&self) -> Vec<(Rect, PaintCmd)> {
         let memory = self.memory.lock();
-        self.graphics.lock().drain(&memory.floating_order).collect()
+        let modal_stack = memory.modal_stack_this_frame();
+        self.graphics
+            .lock()
+            .drain(&memory.floating_layer_order(), &modal_stack)
+            .collect()
+    }
+
+    /// The part of the screen not yet taken up by side, top or bottom panels.
+    /// `CentralPanel` and windows are placed within this.
+    pub fn available_rect(&self) -> Rect {
+        *self.available_rect.lock()
+    }
+
+    /// Shrink `available_rect()` by this panel rectangle.
+    pub(crate) fn allocate_panel(&self, side: Side, panel_rect: Rect) {
+        let mut available_rect = self.available_rect.lock();
+        match side {
+            Side::Left => available_rect.min.x = available_rect.min.x.max(panel_rect.max.x),
+            Side::Right => available_rect.max.x = available_rect.max.x.min(panel_rect.min.x),
+            Side::Top => available_rect.min.y = available_rect.min.y.max(panel_rect.max.y),
+            Side::Bottom => available_rect.max.y = available_rect.max.y.min(panel_rect.min.y),
+        }
+    }
+
+    /// Call this while animating, so that we get another frame even without new input.
+    pub fn request_repaint(&self) {
+        self.output.lock().needs_repaint = true;
     }
 
     /// Is the user interacting with anything?
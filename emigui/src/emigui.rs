@@ -88,6 +88,10 @@ impl Emigui {
             self.ctx.style_ui(region);
         });
 
+        if region.add(Button::new("Reset window positions")).clicked {
+            self.ctx.memory().reset_floating_positions();
+        }
+
         region.collapsing("Fonts", |region| {
             let old_font_definitions = self.ctx.fonts.definitions();
             let mut new_font_definitions = old_font_definitions.clone();
@@ -151,11 +155,14 @@ impl RawInput {
         // TODO: simpler way to show values, e.g. `region.value("Mouse Pos:", self.mouse_pos);
         // TODO: easily change default font!
         region.add(label!("mouse_down: {}", self.mouse_down));
+        region.add(label!("secondary_down: {}", self.secondary_down));
         region.add(label!("mouse_pos: {:.1?}", self.mouse_pos));
         region.add(label!("scroll_delta: {:?}", self.scroll_delta));
+        region.add(label!("scroll_delta_lines: {:?}", self.scroll_delta_lines));
         region.add(label!("screen_size: {:?}", self.screen_size));
         region.add(label!("pixels_per_point: {}", self.pixels_per_point));
         region.add(label!("time: {:.3} s", self.time));
+        region.add(label!("modifiers: {:?}", self.modifiers));
         region.add(label!("events: {:?}", self.events));
         region.add(label!("dropped_files: {:?}", self.dropped_files));
         region.add(label!("hovered_files: {:?}", self.hovered_files));
@@ -167,12 +174,18 @@ impl GuiInput {
         region.add(label!("mouse_down: {}", self.mouse_down));
         region.add(label!("mouse_pressed: {}", self.mouse_pressed));
         region.add(label!("mouse_released: {}", self.mouse_released));
+        region.add(label!("secondary_down: {}", self.secondary_down));
+        region.add(label!("secondary_pressed: {}", self.secondary_pressed));
+        region.add(label!("secondary_released: {}", self.secondary_released));
         region.add(label!("mouse_pos: {:?}", self.mouse_pos));
         region.add(label!("mouse_move: {:?}", self.mouse_move));
         region.add(label!("scroll_delta: {:?}", self.scroll_delta));
+        region.add(label!("scroll_delta_lines: {:?}", self.scroll_delta_lines));
         region.add(label!("screen_size: {:?}", self.screen_size));
         region.add(label!("pixels_per_point: {}", self.pixels_per_point));
         region.add(label!("time: {}", self.time));
+        region.add(label!("dt: {:.3} s", self.dt));
+        region.add(label!("modifiers: {:?}", self.modifiers));
         region.add(label!("events: {:?}", self.events));
         region.add(label!("dropped_files: {:?}", self.dropped_files));
         region.add(label!("hovered_files: {:?}", self.hovered_files));
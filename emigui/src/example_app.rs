@@ -7,6 +7,7 @@ pub struct ExampleWindow {
     checked: bool,
     count: usize,
     radio: usize,
+    toppings: [bool; 3],
     text_inputs: [String; 3],
 
     size: Vec2,
@@ -17,8 +18,15 @@ pub struct ExampleWindow {
     num_columns: usize,
 
     slider_value: usize,
+    log_value: f32,
+    stepped_value: f32,
+    value_range: std::ops::RangeInclusive<f32>,
 
     painting: Painting,
+
+    selected_row: Option<usize>,
+    log_lines: usize,
+    modal_open: bool,
 }
 
 impl Default for ExampleWindow {
@@ -26,6 +34,7 @@ impl Default for ExampleWindow {
         ExampleWindow {
             checked: true,
             radio: 0,
+            toppings: [true, false, false],
             count: 0,
             text_inputs: Default::default(),
 
@@ -37,14 +46,47 @@ impl Default for ExampleWindow {
             num_columns: 2,
 
             slider_value: 100,
+            log_value: 1.0,
+            stepped_value: 5.0,
+            value_range: 25.0..=75.0,
 
             painting: Default::default(),
+
+            selected_row: None,
+            log_lines: 10,
+            modal_open: false,
         }
     }
 }
 
 impl ExampleWindow {
+    fn menu_bar(&mut self, region: &mut Region) {
+        MenuBar::new().show(region, |region| {
+            Menu::new("File").show(region, |region| {
+                if region.add(MenuItem::new("Reset counter")).clicked {
+                    self.count = 0;
+                }
+                region.add(MenuItem::new("Save").shortcut("Ctrl+S").enabled(false));
+                menu::separator(region);
+                Menu::new("Recent").show(region, |region| {
+                    region.add(MenuItem::new("foo.txt"));
+                    region.add(MenuItem::new("bar.txt"));
+                });
+            });
+            Menu::new("View").show(region, |region| {
+                if region
+                    .add(MenuItem::new("Checkbox").checked(self.checked))
+                    .clicked
+                {
+                    self.checked = !self.checked;
+                }
+            });
+        });
+    }
+
     pub fn ui(&mut self, region: &mut Region) {
+        self.menu_bar(region);
+
         region.collapsing("About Emigui", |region| {
             region.add(label!(
                 "Emigui is an experimental immediate mode GUI written in Rust."
@@ -68,6 +110,23 @@ impl ExampleWindow {
             });
 
             region.add(Checkbox::new(&mut self.checked, "checkbox"));
+            region.add(ToggleSwitch::new(&mut self.checked, "toggle switch"));
+
+            let all_toppings = self.toppings.iter().all(|&checked| checked);
+            let some_toppings = self.toppings.iter().any(|&checked| checked) && !all_toppings;
+            let mut checked = all_toppings;
+            if region
+                .add(Checkbox::new(&mut checked, "All toppings").indeterminate(some_toppings))
+                .clicked
+            {
+                self.toppings = [checked; 3];
+            }
+            let toppings = &mut self.toppings;
+            region.indent("toppings", |region| {
+                for (checked, name) in toppings.iter_mut().zip(&["Cheese", "Ham", "Pineapple"]) {
+                    region.add(Checkbox::new(checked, *name));
+                }
+            });
 
             region.horizontal(Align::Min, |region| {
                 if region.add(radio(self.radio == 0, "First")).clicked {
@@ -81,24 +140,73 @@ impl ExampleWindow {
                 }
             });
 
+            region.horizontal(Align::Min, |region| {
+                for (i, text) in ["First", "Second", "Final"].iter().enumerate() {
+                    if region.add(SelectableLabel::new(self.radio == i, *text)).clicked {
+                        self.radio = i;
+                    }
+                }
+                region.add(ToggleButton::new(&mut self.checked, "Toggle the checkbox"));
+            });
+
+            region.add(
+                ComboBox::new(&mut self.radio)
+                    .item(0, "First")
+                    .item(1, "Second")
+                    .item(2, "Final")
+                    .text("Combo box"),
+            );
+
             region.horizontal(Align::Min, |region| {
                 if region
                     .add(Button::new("Click me"))
-                    .tooltip_text("This will just increase a counter.")
+                    .tooltip_text("This will just increase a counter. Right-click the text for more.")
                     .clicked
                 {
                     self.count += 1;
                 }
-                region.add(label!(
-                    "The button have been clicked {} times",
-                    self.count
-                ));
+                let count = &mut self.count;
+                region
+                    .add(label!("The button have been clicked {} times", count))
+                    .context_menu(|region| {
+                        if region.add(MenuItem::new("Add ten")).clicked {
+                            *count += 10;
+                        }
+                        if region.add(MenuItem::new("Reset")).clicked {
+                            *count = 0;
+                        }
+                    });
             });
 
             region.add(Slider::usize(&mut self.slider_value, 1..=1000).text("value"));
-            if region.add(Button::new("Double it")).clicked {
-                self.slider_value *= 2;
-            }
+            region.add(
+                DragValue::usize(&mut self.slider_value)
+                    .clamp_range(1.0..=1000.0)
+                    .prefix("Drag me: "),
+            )
+            .tooltip_text("Drag left or right to change the value. Hold shift for fine adjustment. Double-click to type.");
+            region.horizontal(Align::Min, |region| {
+                if region.add(Button::new("Double it")).clicked {
+                    self.slider_value *= 2;
+                }
+                let can_halve = self.slider_value >= 2;
+                if region
+                    .add(Button::new("Halve it").enabled(can_halve))
+                    .tooltip_text("The value can not go below 1.")
+                    .clicked
+                {
+                    self.slider_value /= 2;
+                }
+                let slider_value = &mut self.slider_value;
+                region
+                    .add(label!("(interactive tooltip)"))
+                    .tooltip(|region| {
+                        region.add_label("You can move the mouse into this tooltip.");
+                        if region.add(Button::new("Reset value")).clicked {
+                            *slider_value = 1;
+                        }
+                    });
+            });
 
             for (i, text) in self.text_inputs.iter_mut().enumerate() {
                 region.horizontal(Align::Min, |region|{
@@ -108,6 +216,48 @@ impl ExampleWindow {
             }
         });
 
+        region.collapsing("Sliders", |region| {
+            region.add_label("Click a slider to move it with the arrow keys. Click a value to type in a new one.");
+            region.add(
+                Slider::f32(&mut self.log_value, 0.001..=1000.0)
+                    .logarithmic(true)
+                    .text("logarithmic"),
+            );
+            region.add(
+                Slider::f32(&mut self.stepped_value, 0.0..=10.0)
+                    .step(0.5)
+                    .precision(1)
+                    .text("step 0.5"),
+            );
+            region.add(
+                RangeSlider::new(&mut self.value_range, 0.0..=100.0)
+                    .precision(0)
+                    .text("range"),
+            );
+            region.horizontal(Align::Min, |region| {
+                region.add(
+                    Slider::f32(&mut self.stepped_value, 0.0..=10.0)
+                        .step(0.5)
+                        .orientation(Direction::Vertical),
+                );
+                region.add(
+                    Slider::f32(&mut self.log_value, 0.001..=1000.0)
+                        .logarithmic(true)
+                        .orientation(Direction::Vertical),
+                );
+            });
+        });
+
+        region.collapsing("Progress", |region| {
+            let progress = self.stepped_value / 10.0;
+            region.add(ProgressBar::new(progress).text(format!("{:.0} %", 100.0 * progress)));
+            region.add(ProgressBar::indeterminate().text("Working..."));
+            region.horizontal(Align::Min, |region| {
+                region.add(Spinner::new());
+                region.add_label("Loading...");
+            });
+        });
+
         region.collapsing("Layouts", |region| {
             region.add(Slider::usize(&mut self.num_columns, 1..=10).text("Columns"));
             region.columns(self.num_columns, |cols| {
@@ -156,6 +306,120 @@ impl ExampleWindow {
                 ScrollArea::default().show(region, |region| {
                     region.add_label(LOREM_IPSUM);
                 });
+
+                region.add_label("Only the visible rows of this list are laid out:");
+                let text_style = TextStyle::Body;
+                let row_height = region.fonts()[text_style].line_spacing();
+                let num_rows = 100_000;
+                ScrollArea::default().id_source("huge_list").show_rows(
+                    region,
+                    row_height,
+                    num_rows,
+                    |region, rows| {
+                        for row in rows {
+                            region.add(
+                                label!("Row {} out of {}", row + 1, num_rows).multiline(false),
+                            );
+                        }
+                    },
+                );
+
+                region.horizontal(Align::Min, |region| {
+                    region.add_label("This log sticks to the bottom as it grows:");
+                    if region.add(Button::new("Add line")).clicked {
+                        self.log_lines += 1;
+                    }
+                });
+                let log_lines = self.log_lines;
+                ScrollArea::default()
+                    .id_source("log")
+                    .max_height(100.0)
+                    .stick_to_bottom(true)
+                    .show(region, |region| {
+                        let mut first_line = None;
+                        for line in 0..log_lines {
+                            let response =
+                                region.add(label!("Log line {}", line + 1).multiline(false));
+                            if line == 0 {
+                                first_line = Some(response);
+                            }
+                        }
+                        if region.add(Button::new("Back to top")).clicked {
+                            if let Some(mut first_line) = first_line {
+                                first_line.scroll_to_me(Align::Min);
+                            }
+                        }
+                    });
+
+                region.add_label(
+                    "Scroll in both directions (hold shift to scroll sideways with the wheel):",
+                );
+                ScrollArea::both()
+                    .id_source("grid")
+                    .max_height(150.0)
+                    .show(region, |region| {
+                        for row in 0..20 {
+                            region.horizontal(Align::Min, |region| {
+                                for col in 0..20 {
+                                    region.add(label!("({}, {})", row, col).multiline(false));
+                                }
+                            });
+                        }
+                    });
+            });
+
+        CollapsingHeader::new("Popups")
+            // .default_open()
+            .show(region, |region| {
+                let response = region.add(Button::new("Toggle popup"));
+                let popup_id = region.make_child_id("example_popup");
+                if response.clicked {
+                    region.memory().toggle_popup(popup_id);
+                }
+                Popup::new(popup_id).show(&response, |region| {
+                    region.add_label("This popup stays on screen, and closes if you click outside it or press Escape.");
+                    region.add(Checkbox::new(&mut self.checked, "checkbox"));
+                });
+
+                if region.add(Button::new("Open modal dialog")).clicked {
+                    self.modal_open = true;
+                }
+            });
+        let mut close_modal = false;
+        Modal::new("example_modal")
+            .open(&mut self.modal_open)
+            .show(region.ctx(), |region| {
+                region.add(label!("Modal dialog").text_style(TextStyle::Heading));
+                region.add_label("Everything behind this dialog is blocked.");
+                region.add_label("Press Escape or the button below to close it.");
+                if region.add(Button::new("Close")).clicked {
+                    close_modal = true;
+                }
+            });
+        if close_modal {
+            self.modal_open = false;
+        }
+
+        CollapsingHeader::new("Table")
+            // .default_open()
+            .show(region, |region| self.table_ui(region));
+
+        CollapsingHeader::new("Tree view")
+            // .default_open()
+            .show(region, |region| {
+                region.add_label("Ctrl-click or Shift-click to select more than one node. Click a node to move around with the arrow keys.");
+                TreeView::new("example_tree")
+                    .multi_select(true)
+                    .show(region, |tree| {
+                        tree.node("fruits", "Fruits", |tree| {
+                            for fruit in &["Apple", "Banana", "Cherry"] {
+                                tree.leaf(fruit, *fruit);
+                            }
+                        });
+                        tree.node("numbers", "Numbers (infinitely deep)", |tree| {
+                            number_node(tree, 1)
+                        });
+                    });
             });
 
         CollapsingHeader::new("Painting")
@@ -201,6 +465,51 @@ impl ExampleWindow {
     }
 }
 
+impl ExampleWindow {
+    fn table_ui(&mut self, region: &mut Region) {
+        region.add_label("Drag the header edges to resize, click a header to sort.");
+
+        let selected_row = &mut self.selected_row;
+        Table::new("example_table")
+            .column(Column::new("Number").default_width(80.0).align(Align::Max))
+            .column(Column::new("Square").default_width(80.0).align(Align::Max))
+            .column(Column::new("Name").default_width(120.0))
+            .max_height(200.0)
+            .show(region, |body| {
+                let mut rows: Vec<usize> = (1..=50).collect();
+                if let Some((column, direction)) = body.sort() {
+                    match column {
+                        2 => rows.sort_by_key(|n| format!("row {}", n)),
+                        _ => rows.sort(),
+                    }
+                    if direction == SortDirection::Descending {
+                        rows.reverse();
+                    }
+                }
+
+                for n in rows {
+                    let response = body.row(*selected_row == Some(n), |cells| {
+                        cells[0].add(label!("{}", n));
+                        cells[1].add(label!("{}", n * n));
+                        cells[2].add(label!("row {}", n));
+                    });
+                    if response.clicked {
+                        *selected_row = Some(n);
+                    }
+                }
+            });
+    }
+}
+
+/// Every number n has the children 2n and 2n + 1.
+/// Only the expanded nodes are ever generated.
+fn number_node(tree: &mut TreeBody<'_>, n: u64) {
+    tree.node(n, n.to_string(), |tree| {
+        number_node(tree, 2 * n);
+        number_node(tree, 2 * n + 1);
+    });
+}
+
 #[derive(Default)]
 struct Painting {
     lines: Vec<Vec<Vec2>>,
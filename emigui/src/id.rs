@@ -31,7 +31,7 @@ use std::{collections::hash_map::DefaultHasher, hash::Hash};
 
 use crate::math::Pos2;
 
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Id(u64);
 
 impl Id {
@@ -43,6 +43,10 @@ impl Id {
         Self(1)
     }
 
+    pub fn context_menu() -> Self {
+        Self(2)
+    }
+
     pub fn new(source: impl Hash) -> Id {
         use std::hash::Hasher;
         let mut hasher = DefaultHasher::new();
@@ -7,6 +7,8 @@ use crate::{math::Rect, Id, PaintCmd};
 pub enum Layer {
     Background,
     Window(Id),
+    /// Dims everything below, and gets all the input
+    Modal(Id),
     /// Tooltips etc
     Popup,
     /// Debug text
@@ -21,6 +23,7 @@ type PaintList = Vec<(Rect, PaintCmd)>;
 pub struct GraphicLayers {
     bg: PaintList,
     windows: HashMap<Id, PaintList>,
+    modals: HashMap<Id, PaintList>,
     popup: PaintList,
     debug: PaintList,
 }
@@ -30,6 +33,7 @@ impl GraphicLayers {
         match layer {
             Layer::Background => &mut self.bg,
             Layer::Window(id) => self.windows.entry(id).or_default(),
+            Layer::Modal(id) => self.modals.entry(id).or_default(),
             Layer::Popup => &mut self.popup,
             Layer::Debug => &mut self.debug,
         }
@@ -38,6 +42,7 @@ impl GraphicLayers {
     pub fn drain(
         &mut self,
         window_oreder: &[Id],
+        modal_order: &[Id],
     ) -> impl ExactSizeIterator<Item = (Rect, PaintCmd)> {
         let mut all_commands: Vec<_> = self.bg.drain(..).collect();
 
@@ -47,6 +52,12 @@ impl GraphicLayers {
             }
         }
 
+        for id in modal_order {
+            if let Some(modal) = self.modals.get_mut(id) {
+                all_commands.append(modal);
+            }
+        }
+
         all_commands.extend(self.popup.drain(..));
         all_commands.extend(self.debug.drain(..));
         all_commands.into_iter()
@@ -23,14 +23,11 @@ pub struct GuiResponse {
 }
 
 impl GuiResponse {
-    /// Show some stuff if the item was hovered
+    /// Show some stuff if the item has been hovered for `Style::tooltip_delay` seconds.
+    /// The tooltip stays open while the mouse moves into it, so it can contain interactive widgets.
+    /// Works for disabled widgets too.
     pub fn tooltip(&mut self, add_contents: impl FnOnce(&mut Region)) -> &mut Self {
-        if self.hovered {
-            if let Some(mouse_pos) = self.ctx.input().mouse_pos {
-                let window_pos = mouse_pos + vec2(16.0, 16.0);
-                show_popup(&self.ctx, window_pos, add_contents);
-            }
-        }
+        crate::containers::tooltip::show(self, add_contents);
         self
     }
 
@@ -40,11 +37,28 @@ impl GuiResponse {
             popup.add(Label::new(text));
         })
     }
+
+    /// Open a menu at the mouse when this item is right-clicked (secondary mouse button).
+    /// Fill it with e.g. `MenuItem`s and `Menu`s (submenus).
+    /// It closes on a click outside it, on Escape or when a `MenuItem` is clicked.
+    pub fn context_menu(&mut self, add_contents: impl FnOnce(&mut Region)) -> &mut Self {
+        crate::containers::menu::context_menu(self, add_contents);
+        self
+    }
+
+    /// Scroll the surrounding `ScrollArea` so that this item is visible.
+    /// `align` decides if the item ends up at the top/left (`Min`), center or bottom/right (`Max`).
+    /// Call it inside the contents of the `ScrollArea` to scroll this frame,
+    /// or after (or outside of) them to scroll next frame.
+    pub fn scroll_to_me(&mut self, align: Align) -> &mut Self {
+        *self.ctx.scroll_target.lock() = Some((self.rect, align));
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub enum Direction {
     Horizontal,
     Vertical,
@@ -92,40 +106,10 @@ pub fn align_rect(rect: &Rect, align: (Align, Align)) -> Rect {
 
 // ----------------------------------------------------------------------------
 
-// TODO: move show_popup, and expand its features (default size, autosize, etc)
-/// Show a pop-over window
+/// Show a pop-over window at the given position.
+/// See `GuiResponse::tooltip` for tooltips.
+/// See `containers::Popup` for popups attached to a widget.
 pub fn show_popup(ctx: &Arc<Context>, window_pos: Pos2, add_contents: impl FnOnce(&mut Region)) {
-    let layer = Layer::Popup;
-    let where_to_put_background = ctx.graphics.lock().layer(layer).len();
-
-    let style = ctx.style();
-    let window_padding = style.window_padding;
-
-    let size = vec2(ctx.input.screen_size.x.min(350.0), f32::INFINITY); // TODO: popup/tooltip width
-    let inner_rect = Rect::from_min_size(window_pos + window_padding, size);
-    let mut contents_region = Region::new(ctx.clone(), layer, Id::popup(), inner_rect);
-
-    add_contents(&mut contents_region);
-
-    // Now insert popup background:
-
-    // TODO: handle the last item_spacing in a nicer way
-    let inner_size = contents_region.bounding_size() - style.item_spacing;
-    let outer_size = inner_size + 2.0 * window_padding;
-
-    let rect = Rect::from_min_size(window_pos, outer_size);
-
-    let mut graphics = ctx.graphics.lock();
-    graphics.layer(layer).insert(
-        where_to_put_background,
-        (
-            Rect::everything(),
-            PaintCmd::Rect {
-                corner_radius: 5.0,
-                fill_color: Some(style.background_fill_color()),
-                outline: Some(Outline::new(1.0, color::WHITE)),
-                rect,
-            },
-        ),
-    );
+    let max_width = 350.0; // TODO: popup/tooltip width
+    crate::containers::popup::show_at(ctx, Id::popup(), window_pos, 0.0, max_width, add_contents);
 }
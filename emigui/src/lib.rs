@@ -32,7 +32,7 @@ pub use {
     fonts::{FontDefinitions, Fonts, TextStyle},
     id::Id,
     layers::*,
-    layout::{Align, GuiResponse},
+    layout::{Align, Direction, GuiResponse},
     math::*,
     memory::Memory,
     mesher::{Mesh, PaintBatches, Vertex},
@@ -1,4 +1,6 @@
-use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, RangeInclusive, Sub, SubAssign,
+};
 
 #[derive(Clone, Copy, Default, Deserialize, Serialize)]
 pub struct Vec2 {
@@ -99,6 +101,29 @@ impl Vec2 {
     }
 }
 
+/// `v[0]` is `x`, `v[1]` is `y`.
+impl Index<usize> for Vec2 {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vec2 index out of bounds: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec2 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vec2 index out of bounds: {}", index),
+        }
+    }
+}
+
 impl PartialEq for Vec2 {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y
@@ -253,6 +278,29 @@ impl Pos2 {
     }
 }
 
+/// `p[0]` is `x`, `p[1]` is `y`.
+impl Index<usize> for Pos2 {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Pos2 index out of bounds: {}", index),
+        }
+    }
+}
+
+impl IndexMut<usize> for Pos2 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Pos2 index out of bounds: {}", index),
+        }
+    }
+}
+
 impl PartialEq for Pos2 {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y
@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 
 use crate::{
-    containers::{collapsing_header, floating, resize, scroll_area},
+    containers::{
+        collapsing_header, dock, floating, menu, modal, popup, resize, scroll_area, table, tooltip,
+        tree_view,
+    },
+    widgets::{color_picker, combo_box, drag_value, toggle_switch},
     Id, Layer, Pos2, Rect,
 };
 
@@ -17,13 +21,107 @@ pub struct Memory {
     pub(crate) collapsing_headers: HashMap<Id, collapsing_header::State>,
     pub(crate) scroll_areas: HashMap<Id, scroll_area::State>,
     pub(crate) resize: HashMap<Id, resize::State>,
+    pub(crate) tables: HashMap<Id, table::State>,
+    pub(crate) tree_views: HashMap<Id, tree_view::State>,
+    pub(crate) combo_boxes: HashMap<Id, combo_box::State>,
+    pub(crate) toggle_switches: HashMap<Id, toggle_switch::State>,
+    pub(crate) drag_values: HashMap<Id, drag_value::State>,
+
+    /// The `ColorPicker` whose popup is open, if any.
+    pub(crate) color_picker: Option<color_picker::State>,
     floating: HashMap<Id, floating::State>,
+    pub(crate) docks: HashMap<Id, dock::State>,
+
+    /// The dock areas shown so far this frame. Top (painted last) is last.
+    pub(crate) docks_this_frame: Vec<Id>,
+
+    /// Where each docked window should be shown this frame.
+    pub(crate) docked_windows: HashMap<Id, dock::DockedWindow>,
+
+    /// The floating (window) currently being dragged, if any.
+    pub(crate) dragged_floating: Option<Id>,
 
     /// Top is last
     pub floating_order: Vec<Id>,
+
+    pub(crate) modals: HashMap<Id, modal::State>,
+
+    /// The popups that are open.
+    pub(crate) popups: HashMap<Id, popup::State>,
+
+    /// The menus that are open, outermost first.
+    /// Only one menu (and its submenus) is open at a time.
+    pub(crate) open_menus: Vec<Id>,
+
+    /// Where the context menu was opened, if it is open.
+    pub(crate) context_menu: Option<menu::ContextMenuState>,
+
+    /// The tooltip being shown, or waiting to be shown.
+    pub(crate) tooltip: Option<tooltip::State>,
+
+    /// The modals that were shown last frame. Top is last, and gets all the input.
+    pub(crate) modal_stack: Vec<Id>,
+
+    /// The modals shown so far this frame.
+    pub(crate) modals_this_frame: Vec<Id>,
+
+    /// Did a widget use Escape this frame (e.g. to cancel an edit)?
+    /// Then it shouldn't also close the modal the widget is in.
+    pub(crate) used_escape: bool,
 }
 
 impl Memory {
+    pub(crate) fn begin_frame(&mut self) {
+        self.modal_stack = self.modal_stack_this_frame();
+        self.modals_this_frame.clear();
+        self.used_escape = false;
+        self.docks_this_frame.clear();
+        let modal_stack = &self.modal_stack;
+        self.modals.retain(|id, _| modal_stack.contains(id));
+
+        // Close the popups that weren't shown last frame:
+        self.popups.retain(|_, state| state.shown);
+        for state in self.popups.values_mut() {
+            state.shown = false;
+        }
+
+        // Forget the menus that went away with their popups:
+        let popups = &self.popups;
+        if let Some(depth) = self
+            .open_menus
+            .iter()
+            .position(|id| !popups.contains_key(id))
+        {
+            self.open_menus.truncate(depth);
+        }
+
+        // Forget the tooltip if its widget was not hovered last frame:
+        if let Some(tooltip) = &mut self.tooltip {
+            if tooltip.alive {
+                tooltip.alive = false;
+            } else {
+                self.tooltip = None;
+            }
+        }
+    }
+
+    /// The modals shown this frame, bottom first.
+    /// Modals keep their place in the stack, and newly opened ones go on top.
+    pub(crate) fn modal_stack_this_frame(&self) -> Vec<Id> {
+        let mut stack: Vec<Id> = self
+            .modal_stack
+            .iter()
+            .filter(|id| self.modals_this_frame.contains(id))
+            .copied()
+            .collect();
+        for id in &self.modals_this_frame {
+            if !stack.contains(id) {
+                stack.push(*id);
+            }
+        }
+        stack
+    }
+
     pub fn get_floating(&mut self, id: Id) -> Option<floating::State> {
         self.floating.get(&id).cloned()
     }
@@ -35,9 +133,51 @@ impl Memory {
         }
     }
 
+    /// Make sure all floatings can still be grabbed, e.g. after the screen was resized.
+    pub(crate) fn constrain_floatings_to(&mut self, rect: Rect) {
+        for state in self.floating.values_mut() {
+            state.constrain_to(rect);
+        }
+    }
+
+    /// Forget where all floatings (windows) are,
+    /// so they go back to their default positions.
+    pub fn reset_floating_positions(&mut self) {
+        self.floating.clear();
+        self.floating_order.clear();
+        self.dragged_floating = None;
+    }
+
+    /// Stop tracking a floating, e.g. because it was docked.
+    pub(crate) fn remove_floating(&mut self, id: Id) {
+        self.floating.remove(&id);
+        self.floating_order.retain(|x| *x != id);
+    }
+
+    /// The layout of a `DockArea`.
+    pub fn dock_state(&self, id: Id) -> Option<dock::State> {
+        self.docks.get(&id).cloned()
+    }
+
+    /// Replace the layout of a `DockArea`, e.g. with one loaded from disk.
+    pub fn set_dock_state(&mut self, id: Id, state: dock::State) {
+        self.docks.insert(id, state);
+    }
+
     /// TODO: call once at the start of the frame for the current mouse pos
     pub fn layer_at(&self, pos: Pos2) -> Layer {
-        for floating_id in self.floating_order.iter().rev() {
+        if self.popups.values().any(|state| state.rect.contains(pos)) {
+            return Layer::Popup;
+        }
+        if let Some(tooltip) = &self.tooltip {
+            if tooltip.rect.contains(pos) {
+                return Layer::Popup;
+            }
+        }
+        if let Some(&top_modal) = self.modal_stack.last() {
+            return Layer::Modal(top_modal);
+        }
+        for floating_id in self.floating_layer_order().iter().rev() {
             if let Some(state) = self.floating.get(floating_id) {
                 let rect = Rect::from_min_size(state.pos, state.size);
                 if rect.contains(pos) {
@@ -48,6 +188,49 @@ impl Memory {
         Layer::Background
     }
 
+    pub fn is_popup_open(&self, id: Id) -> bool {
+        self.popups.contains_key(&id)
+    }
+
+    pub fn open_popup(&mut self, id: Id) {
+        self.popups.entry(id).or_default().shown = true;
+    }
+
+    pub fn close_popup(&mut self, id: Id) {
+        self.popups.remove(&id);
+    }
+
+    pub fn toggle_popup(&mut self, id: Id) {
+        if self.is_popup_open(id) {
+            self.close_popup(id);
+        } else {
+            self.open_popup(id);
+        }
+    }
+
+    /// Close the menu at `depth` in `open_menus`, and all its submenus.
+    pub(crate) fn close_menus_from(&mut self, depth: usize) {
+        let depth = depth.min(self.open_menus.len());
+        for id in self.open_menus.drain(depth..) {
+            self.popups.remove(&id);
+        }
+    }
+
+    /// Close all menus, e.g. after the user picked something from one.
+    pub fn close_menus(&mut self) {
+        self.close_menus_from(0);
+    }
+
+    /// The order in which floatings are painted and hit-tested, bottom first.
+    /// This is `floating_order` sorted by each floatings `floating::Order`.
+    pub fn floating_layer_order(&self) -> Vec<Id> {
+        let mut order = self.floating_order.clone();
+        // Stable sort, so we keep the click order within each `floating::Order`:
+        order.sort_by_key(|id| self.floating.get(id).map(|state| state.order));
+        order
+    }
+
+    /// Put this floating (window) on top of the others with the same `floating::Order`.
     pub fn move_floating_to_top(&mut self, id: Id) {
         if self.floating_order.last() == Some(&id) {
             return; // common case early-out
@@ -57,4 +240,12 @@ impl Memory {
         }
         self.floating_order.push(id);
     }
+
+    /// Put this floating (window) below the others with the same `floating::Order`.
+    pub fn move_floating_to_bottom(&mut self, id: Id) {
+        if let Some(index) = self.floating_order.iter().position(|x| *x == id) {
+            self.floating_order.remove(index);
+            self.floating_order.insert(0, id);
+        }
+    }
 }
@@ -9,7 +9,9 @@ use crate::{
     Outline,
 };
 
-const WHITE_UV: (u16, u16) = (1, 1);
+/// The texture coordinates of a white texel in the font texture.
+/// Use it for vertices that should only get their color from `Vertex::color`.
+pub const WHITE_UV: (u16, u16) = (1, 1);
 
 #[derive(Clone, Copy, Debug, Default, Serialize)]
 pub struct Vertex {
@@ -216,6 +218,18 @@ impl Path {
     /// 3 * TAU / 4 = up
     ///    quadrant 3 up rigth
     /// 4 * TAU / 4 = right
+    /// An arc from `start_angle` to `end_angle` (in radians, clockwise on screen).
+    pub fn add_arc(&mut self, center: Pos2, radius: f32, start_angle: f32, end_angle: f32) {
+        let n = ((end_angle - start_angle).abs() / TAU * 32.0)
+            .ceil()
+            .max(1.0) as usize; // Same resolution as add_circle
+        for i in 0..=n {
+            let angle = remap(i as f32, 0.0..=n as f32, start_angle..=end_angle);
+            let normal = vec2(angle.cos(), angle.sin());
+            self.add_point(center + radius * normal, normal);
+        }
+    }
+
     pub fn add_circle_quadrant(&mut self, center: Pos2, radius: f32, quadrant: f32) {
         let n = 8;
         const RIGHT_ANGLE: f32 = TAU / 4.0;
@@ -23,6 +23,9 @@ pub struct Style {
     /// The text starts after this many pixels.
     pub start_icon_width: f32,
 
+    /// Mouse must be this close to the edge of a panel to resize it.
+    pub resize_grab_radius: f32,
+
     // -----------------------------------------------
     // Purely visual:
     /// For stuff like check marks in check boxes.
@@ -35,8 +38,26 @@ pub struct Style {
     /// How many seconds a typical animation should last
     pub animation_time: f32,
 
+    /// How many seconds the mouse must hover a widget before its tooltip is shown.
+    pub tooltip_delay: f32,
+
     pub window: Window,
 
+    // -----------------------------------------------
+    // Colors:
+    /// e.g. the background of the slider
+    pub background_fill_color: Color,
+
+    pub text_color: Color,
+
+    /// Text of widgets that can't be interacted with
+    pub disabled_text_color: Color,
+
+    /// Fill color of interactive components that are neither hovered nor active.
+    pub inactive_fill_color: Color,
+    pub hovered_fill_color: Color,
+    pub active_fill_color: Color,
+
     // -----------------------------------------------
     // Debug rendering:
     pub debug_regions: bool,
@@ -56,11 +77,19 @@ impl Default for Style {
             indent: 21.0,
             clickable_diameter: 22.0,
             start_icon_width: 16.0,
+            resize_grab_radius: 5.0,
             line_width: 1.0,
             cursor_blink_hz: 1.0,
             text_cursor_width: 2.0,
             animation_time: 1.0 / 20.0,
+            tooltip_delay: 0.5,
             window: Window::default(),
+            background_fill_color: gray(34, 250),
+            text_color: gray(255, 200),
+            disabled_text_color: gray(140, 200),
+            inactive_fill_color: srgba(60, 60, 80, 255),
+            hovered_fill_color: srgba(100, 100, 150, 255),
+            active_fill_color: srgba(120, 120, 200, 255),
             debug_regions: false,
         }
     }
@@ -77,21 +106,26 @@ impl Default for Window {
 impl Style {
     /// e.g. the background of the slider
     pub fn background_fill_color(&self) -> Color {
-        gray(34, 250)
+        self.background_fill_color
     }
 
     pub fn text_color(&self) -> Color {
-        gray(255, 200)
+        self.text_color
+    }
+
+    /// Text of widgets that can't be interacted with
+    pub fn disabled_text_color(&self) -> Color {
+        self.disabled_text_color
     }
 
     /// Fill color of the interactive part of a component (button, slider grab, checkbox, ...)
     pub fn interact_fill_color(&self, interact: &InteractInfo) -> Option<Color> {
         if interact.active {
-            Some(srgba(120, 120, 200, 255))
+            Some(self.active_fill_color)
         } else if interact.hovered {
-            Some(srgba(100, 100, 150, 255))
+            Some(self.hovered_fill_color)
         } else {
-            Some(srgba(60, 60, 80, 255))
+            Some(self.inactive_fill_color)
         }
     }
 
@@ -165,22 +199,56 @@ impl Style {
         }
 
 
-        region.add(Slider::f32(&mut self.item_spacing.x,     0.0..=10.0).text("item_spacing.x").precision(0));
-        region.add(Slider::f32(&mut self.item_spacing.y,     0.0..=10.0).text("item_spacing.y").precision(0));
-        region.add(Slider::f32(&mut self.window_padding.x,   0.0..=10.0).text("window_padding.x").precision(0));
-        region.add(Slider::f32(&mut self.window_padding.y,   0.0..=10.0).text("window_padding.y").precision(0));
+        vec2_ui(region, &mut self.item_spacing,   0.0..=10.0, "item_spacing");
+        vec2_ui(region, &mut self.window_padding, 0.0..=10.0, "window_padding");
+        vec2_ui(region, &mut self.button_padding, 0.0..=20.0, "button_padding");
         region.add(Slider::f32(&mut self.indent,             0.0..=100.0).text("indent").precision(0));
-        region.add(Slider::f32(&mut self.button_padding.x,   0.0..=20.0).text("button_padding.x").precision(0));
-        region.add(Slider::f32(&mut self.button_padding.y,   0.0..=20.0).text("button_padding.y").precision(0));
         region.add(Slider::f32(&mut self.clickable_diameter, 0.0..=60.0).text("clickable_diameter").precision(0));
         region.add(Slider::f32(&mut self.start_icon_width,   0.0..=60.0).text("start_icon_width").precision(0));
+        region.add(Slider::f32(&mut self.resize_grab_radius, 0.0..=20.0).text("resize_grab_radius").precision(0));
         region.add(Slider::f32(&mut self.line_width,         0.0..=10.0).text("line_width").precision(0));
         region.add(Slider::f32(&mut self.animation_time,     0.0..=1.0).text("animation_time").precision(2));
+        region.add(Slider::f32(&mut self.tooltip_delay,      0.0..=2.0).text("tooltip_delay").precision(2));
 
 
         // TODO: region.section("Heading", |ui| ui.add(contents))
+        region.add(Separator::new());
+        region.add(label!("Colors:").text_style(TextStyle::Heading));
+        region.add(ColorPicker::new(&mut self.background_fill_color).text("background_fill_color"));
+        region.add(ColorPicker::new(&mut self.text_color).text("text_color"));
+        region.add(ColorPicker::new(&mut self.disabled_text_color).text("disabled_text_color"));
+        region.add(ColorPicker::new(&mut self.inactive_fill_color).text("inactive_fill_color"));
+        region.add(ColorPicker::new(&mut self.hovered_fill_color).text("hovered_fill_color"));
+        region.add(ColorPicker::new(&mut self.active_fill_color).text("active_fill_color"));
+
         region.add(Separator::new());
         region.add(label!("Debug:").text_style(TextStyle::Heading));
         region.add(Checkbox::new(&mut self.debug_regions, "debug_regions"));
     }
 }
+
+/// Edit the x and y of a `Vec2` side by side.
+fn vec2_ui(
+    region: &mut crate::Region,
+    value: &mut Vec2,
+    range: std::ops::RangeInclusive<f32>,
+    text: &str,
+) {
+    use crate::{widgets::*, *};
+    let range = (*range.start() as f64)..=(*range.end() as f64);
+    region.horizontal(Align::Min, |region| {
+        region.add(
+            DragValue::f32(&mut value.x)
+                .prefix("x: ")
+                .clamp_range(range.clone())
+                .precision(0),
+        );
+        region.add(
+            DragValue::f32(&mut value.y)
+                .prefix("y: ")
+                .clamp_range(range)
+                .precision(0),
+        );
+        region.add(Label::new(text).multiline(false));
+    });
+}
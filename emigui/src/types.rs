@@ -15,12 +15,19 @@ pub struct RawInput {
     /// Is the button currently down?
     pub mouse_down: bool,
 
+    /// Is the secondary (usually right) mouse button currently down?
+    pub secondary_down: bool,
+
     /// Current position of the mouse in points.
     pub mouse_pos: Option<Pos2>,
 
-    /// How many pixels the user scrolled
+    /// How many pixels the user scrolled, e.g. with a trackpad.
     pub scroll_delta: Vec2,
 
+    /// How many lines the user scrolled with a mouse wheel.
+    /// Unlike `scroll_delta` this is animated smoothly by `ScrollArea`.
+    pub scroll_delta_lines: Vec2,
+
     /// Size of the screen in points.
     pub screen_size: Vec2,
 
@@ -30,6 +37,9 @@ pub struct RawInput {
     /// Time in seconds. Relative to whatever. Used for animation.
     pub time: f64,
 
+    /// Which modifier keys are held down.
+    pub modifiers: Modifiers,
+
     /// Files has been dropped into the window.
     pub dropped_files: Vec<std::path::PathBuf>,
 
@@ -56,6 +66,15 @@ pub struct GuiInput {
     /// The mouse went from down to !down
     pub mouse_released: bool,
 
+    /// Is the secondary (usually right) mouse button currently down?
+    pub secondary_down: bool,
+
+    /// The secondary mouse button went from !down to down, e.g. to open a context menu.
+    pub secondary_pressed: bool,
+
+    /// The secondary mouse button went from down to !down
+    pub secondary_released: bool,
+
     /// Current position of the mouse in points.
     /// None for touch screens when finger is not down.
     pub mouse_pos: Option<Pos2>,
@@ -63,9 +82,13 @@ pub struct GuiInput {
     /// How much the mouse moved compared to last frame, in points.
     pub mouse_move: Vec2,
 
-    /// How many pixels the user scrolled
+    /// How many pixels the user scrolled, e.g. with a trackpad.
     pub scroll_delta: Vec2,
 
+    /// How many lines the user scrolled with a mouse wheel.
+    /// Unlike `scroll_delta` this is animated smoothly by `ScrollArea`.
+    pub scroll_delta_lines: Vec2,
+
     /// Size of the screen in points.
     pub screen_size: Vec2,
 
@@ -75,6 +98,12 @@ pub struct GuiInput {
     /// Time in seconds. Relative to whatever. Used for animation.
     pub time: f64,
 
+    /// Time since last frame, in seconds.
+    pub dt: f32,
+
+    /// Which modifier keys are held down.
+    pub modifiers: Modifiers,
+
     /// Files has been dropped into the window.
     pub dropped_files: Vec<std::path::PathBuf>,
 
@@ -85,6 +114,17 @@ pub struct GuiInput {
     pub events: Vec<Event>,
 }
 
+/// State of the modifier keys.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Modifiers {
+    pub alt: bool,
+    pub ctrl: bool,
+    pub shift: bool,
+    /// Windows key or Mac Command key
+    pub logo: bool,
+}
+
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Event {
@@ -133,12 +173,18 @@ impl GuiInput {
             mouse_down: new.mouse_down && new.mouse_pos.is_some(),
             mouse_pressed: !last.mouse_down && new.mouse_down,
             mouse_released: last.mouse_down && !new.mouse_down,
+            secondary_down: new.secondary_down && new.mouse_pos.is_some(),
+            secondary_pressed: !last.secondary_down && new.secondary_down,
+            secondary_released: last.secondary_down && !new.secondary_down,
             mouse_pos: new.mouse_pos,
             mouse_move,
             scroll_delta: new.scroll_delta,
+            scroll_delta_lines: new.scroll_delta_lines,
             screen_size: new.screen_size,
             pixels_per_point: new.pixels_per_point,
             time: new.time,
+            dt: (new.time - last.time) as f32,
+            modifiers: new.modifiers,
             dropped_files: new.dropped_files.clone(),
             hovered_files: new.hovered_files.clone(),
             events: new.events.clone(),
@@ -155,6 +201,10 @@ pub struct Output {
 
     /// Response to Event::Copy or Event::Cut. Ignore if empty.
     pub copied_text: String,
+
+    /// Something is animating, so please paint another frame soon,
+    /// even if there is no new input.
+    pub needs_repaint: bool,
 }
 
 #[derive(Clone, Copy, Serialize)]
@@ -163,7 +213,14 @@ pub enum CursorIcon {
     Default,
     /// Pointing hand, used for e.g. web links
     PointingHand,
+    /// Resize left-right
+    ResizeHorizontal,
+    /// Resize up-right and down-left
+    ResizeNeSw,
+    /// Resize up-left and down-right
     ResizeNwSe,
+    /// Resize up-down
+    ResizeVertical,
     Text,
 }
 
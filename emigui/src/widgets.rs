@@ -7,8 +7,20 @@ use crate::{
     *,
 };
 
+pub(crate) mod color_picker;
+pub(crate) mod combo_box;
+pub(crate) mod drag_value;
+mod progress;
 mod text_edit;
-pub use text_edit::*;
+pub(crate) mod toggle_switch;
+pub use {
+    color_picker::ColorPicker,
+    combo_box::ComboBox,
+    drag_value::DragValue,
+    progress::{ProgressBar, Spinner},
+    text_edit::*,
+    toggle_switch::ToggleSwitch,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -134,6 +146,7 @@ impl Widget for Hyperlink {
 pub struct Button {
     text: String,
     text_color: Option<Color>,
+    enabled: bool,
 }
 
 impl Button {
@@ -141,6 +154,7 @@ impl Button {
         Button {
             text: text.into(),
             text_color: None,
+            enabled: true,
         }
     }
 
@@ -148,30 +162,186 @@ impl Button {
         self.text_color = Some(text_color);
         self
     }
+
+    /// If false, the button is grayed out and can't be clicked.
+    /// It is still hovered, so it can still show a tooltip (e.g. explaining why it is disabled).
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
 }
 
 impl Widget for Button {
     fn ui(self, region: &mut Region) -> GuiResponse {
-        let id = region.make_position_id();
-        let text_style = TextStyle::Button;
-        let font = &region.fonts()[text_style];
-        let (text, text_size) = font.layout_multiline(&self.text, region.available_width());
-        let padding = region.style().button_padding;
-        let mut size = text_size + 2.0 * padding;
-        size.y = size.y.max(region.style().clickable_diameter);
-        let interact = region.reserve_space(size, Some(id));
-        let mut text_cursor = interact.rect.left_center() + vec2(padding.x, -0.5 * text_size.y);
-        text_cursor.y += 2.0; // TODO: why is this needed?
+        button_ui(
+            region,
+            &self.text,
+            self.text_color,
+            self.enabled,
+            true,
+            |_| false,
+        )
+    }
+}
+
+/// The layout and painting shared by `Button`, `ToggleButton` and `SelectableLabel`.
+/// `frame`: always paint the background, or only when hovered or selected.
+/// `selected` is called once we know if we were clicked, so a toggle is painted right away.
+fn button_ui(
+    region: &mut Region,
+    text: &str,
+    text_color: Option<Color>,
+    enabled: bool,
+    frame: bool,
+    selected: impl FnOnce(&InteractInfo) -> bool,
+) -> GuiResponse {
+    let id = region.make_position_id();
+    let text_style = TextStyle::Button;
+    let font = &region.fonts()[text_style];
+    let (text, text_size) = font.layout_multiline(text, region.available_width());
+    let padding = region.style().button_padding;
+    let mut size = text_size + 2.0 * padding;
+    size.y = size.y.max(region.style().clickable_diameter);
+    let interaction_id = if enabled { Some(id) } else { None };
+    let interact = region.reserve_space(size, interaction_id);
+    let mut text_cursor = interact.rect.left_center() + vec2(padding.x, -0.5 * text_size.y);
+    text_cursor.y += 2.0; // TODO: why is this needed?
+
+    // Selected buttons look pressed in:
+    let look = InteractInfo {
+        active: interact.active || selected(&interact),
+        ..enabled_look(&interact, enabled)
+    };
+    if frame || look.active || look.hovered {
+        let outline = if frame {
+            region.style().interact_outline(&look)
+        } else {
+            None
+        };
         region.add_paint_cmd(PaintCmd::Rect {
-            corner_radius: region.style().interact_corner_radius(&interact),
-            fill_color: region.style().interact_fill_color(&interact),
-            outline: region.style().interact_outline(&interact),
+            corner_radius: region.style().interact_corner_radius(&look),
+            fill_color: region.style().interact_fill_color(&look),
+            outline,
             rect: interact.rect,
         });
-        let stroke_color = region.style().interact_stroke_color(&interact);
-        let text_color = self.text_color.unwrap_or(stroke_color);
-        region.add_text(text_cursor, text_style, text, Some(text_color));
-        region.response(interact)
+    }
+    let stroke_color = enabled_stroke_color(region.style(), &look, enabled);
+    let text_color = text_color.unwrap_or(stroke_color);
+    region.add_text(text_cursor, text_style, text, Some(text_color));
+    region.response(interact)
+}
+
+/// Disabled widgets are still hovered, so they can show a tooltip, but they shouldn't look it.
+pub(crate) fn enabled_look(interact: &InteractInfo, enabled: bool) -> InteractInfo {
+    InteractInfo {
+        hovered: interact.hovered && enabled,
+        ..*interact
+    }
+}
+
+/// Stroke and text color of an interactive widget, grayed out if it is disabled.
+pub(crate) fn enabled_stroke_color(style: &Style, look: &InteractInfo, enabled: bool) -> Color {
+    if enabled {
+        style.interact_stroke_color(look)
+    } else {
+        style.disabled_text_color()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A button that stays pressed in while `*selected` is true. Clicking it toggles `*selected`.
+pub struct ToggleButton<'a> {
+    selected: &'a mut bool,
+    text: String,
+    text_color: Option<Color>,
+    enabled: bool,
+}
+
+impl<'a> ToggleButton<'a> {
+    pub fn new(selected: &'a mut bool, text: impl Into<String>) -> Self {
+        ToggleButton {
+            selected,
+            text: text.into(),
+            text_color: None,
+            enabled: true,
+        }
+    }
+
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// If false, the button is grayed out and can't be toggled, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<'a> Widget for ToggleButton<'a> {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let ToggleButton {
+            selected,
+            text,
+            text_color,
+            enabled,
+        } = self;
+        button_ui(region, &text, text_color, enabled, true, |interact| {
+            if interact.clicked {
+                *selected = !*selected;
+            }
+            *selected
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Text that is highlighted when selected or hovered, e.g. for an item in a list.
+/// It doesn't change `selected` by itself: check if it was `clicked`.
+#[derive(Debug)]
+pub struct SelectableLabel {
+    selected: bool,
+    text: String,
+    text_color: Option<Color>,
+    enabled: bool,
+}
+
+impl SelectableLabel {
+    pub fn new(selected: bool, text: impl Into<String>) -> Self {
+        SelectableLabel {
+            selected,
+            text: text.into(),
+            text_color: None,
+            enabled: true,
+        }
+    }
+
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// If false, the label is grayed out and can't be clicked, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl Widget for SelectableLabel {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let selected = self.selected;
+        button_ui(
+            region,
+            &self.text,
+            self.text_color,
+            self.enabled,
+            false,
+            |_| selected,
+        )
     }
 }
 
@@ -180,16 +350,20 @@ impl Widget for Button {
 #[derive(Debug)]
 pub struct Checkbox<'a> {
     checked: &'a mut bool,
+    indeterminate: bool,
     text: String,
     text_color: Option<Color>,
+    enabled: bool,
 }
 
 impl<'a> Checkbox<'a> {
     pub fn new(checked: &'a mut bool, text: impl Into<String>) -> Self {
         Checkbox {
             checked,
+            indeterminate: false,
             text: text.into(),
             text_color: None,
+            enabled: true,
         }
     }
 
@@ -197,6 +371,19 @@ impl<'a> Checkbox<'a> {
         self.text_color = Some(text_color);
         self
     }
+
+    /// Show a dash instead of a check mark, e.g. because only some of the children are checked.
+    /// Clicking still just toggles `checked`, so it is up to you to update whatever this summarizes.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// If false, the checkbox is grayed out and can't be clicked, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
 }
 
 impl<'a> Widget for Checkbox<'a> {
@@ -210,7 +397,7 @@ impl<'a> Widget for Checkbox<'a> {
                 + vec2(region.style().start_icon_width, 0.0)
                 + text_size
                 + region.style().button_padding,
-            Some(id),
+            if self.enabled { Some(id) } else { None },
         );
         let text_cursor = interact.rect.min
             + region.style().button_padding
@@ -218,17 +405,27 @@ impl<'a> Widget for Checkbox<'a> {
         if interact.clicked {
             *self.checked = !*self.checked;
         }
+        let look = enabled_look(&interact, self.enabled);
         let (small_icon_rect, big_icon_rect) = region.style().icon_rectangles(&interact.rect);
         region.add_paint_cmd(PaintCmd::Rect {
             corner_radius: 3.0,
-            fill_color: region.style().interact_fill_color(&interact),
+            fill_color: region.style().interact_fill_color(&look),
             outline: None,
             rect: big_icon_rect,
         });
 
-        let stroke_color = region.style().interact_stroke_color(&interact);
+        let stroke_color = enabled_stroke_color(region.style(), &look, self.enabled);
 
-        if *self.checked {
+        if self.indeterminate {
+            region.add_paint_cmd(PaintCmd::line_segment(
+                (
+                    pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                    pos2(small_icon_rect.right(), small_icon_rect.center().y),
+                ),
+                stroke_color,
+                region.style().line_width,
+            ));
+        } else if *self.checked {
             region.add_paint_cmd(PaintCmd::Line {
                 points: vec![
                     pos2(small_icon_rect.left(), small_icon_rect.center().y),
@@ -253,6 +450,7 @@ pub struct RadioButton {
     checked: bool,
     text: String,
     text_color: Option<Color>,
+    enabled: bool,
 }
 
 impl RadioButton {
@@ -261,6 +459,7 @@ impl RadioButton {
             checked,
             text: text.into(),
             text_color: None,
+            enabled: true,
         }
     }
 
@@ -268,6 +467,12 @@ impl RadioButton {
         self.text_color = Some(text_color);
         self
     }
+
+    /// If false, the radio button is grayed out and can't be clicked, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
 }
 
 pub fn radio(checked: bool, text: impl Into<String>) -> RadioButton {
@@ -285,14 +490,15 @@ impl Widget for RadioButton {
                 + vec2(region.style().start_icon_width, 0.0)
                 + text_size
                 + region.style().button_padding,
-            Some(id),
+            if self.enabled { Some(id) } else { None },
         );
         let text_cursor = interact.rect.min
             + region.style().button_padding
             + vec2(region.style().start_icon_width, 0.0);
 
-        let fill_color = region.style().interact_fill_color(&interact);
-        let stroke_color = region.style().interact_stroke_color(&interact);
+        let look = enabled_look(&interact, self.enabled);
+        let fill_color = region.style().interact_fill_color(&look);
+        let stroke_color = enabled_stroke_color(region.style(), &look, self.enabled);
 
         let (small_icon_rect, big_icon_rect) = region.style().icon_rectangles(&interact.rect);
 
@@ -333,6 +539,10 @@ pub struct Slider<'a> {
     text_color: Option<Color>,
     text_on_top: Option<bool>,
     id: Option<Id>,
+    logarithmic: bool,
+    orientation: Direction,
+    step: Option<f32>,
+    enabled: bool,
 }
 
 impl<'a> Slider<'a> {
@@ -348,6 +558,10 @@ impl<'a> Slider<'a> {
             text_on_top: None,
             text_color: None,
             id: None,
+            logarithmic: false,
+            orientation: Direction::Horizontal,
+            step: None,
+            enabled: true,
         }
     }
 
@@ -404,114 +618,561 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Make the slider logarithmic, which is good for ranges spanning
+    /// many orders of magnitude, like `0.001..=1000.0`.
+    /// Only works for positive ranges. Other ranges stay linear.
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.logarithmic = logarithmic;
+        self
+    }
+
+    /// Horizontal (default) or vertical.
+    /// A vertical slider has the smallest value at the bottom, and any text on top.
+    pub fn orientation(mut self, orientation: Direction) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Snap the value to multiples of `step` (counting from the start of the range).
+    /// This is also how much the arrow keys change the value.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// If false, the slider is grayed out and can't be changed, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
     fn get_value_f32(&mut self) -> f32 {
         (self.get_set_value)(None)
     }
 
     fn set_value_f32(&mut self, mut value: f32) {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        if let Some(step) = self.step {
+            if step > 0.0 {
+                value = min + ((value - min) / step).round() * step;
+            }
+        }
         if self.precision == 0 {
             value = value.round();
         }
+        value = value.max(min).min(max);
         (self.get_set_value)(Some(value));
     }
+
+    fn is_logarithmic(&self) -> bool {
+        self.logarithmic && *self.range.start() > 0.0 && *self.range.end() > 0.0
+    }
+
+    /// Where along the slider `value` is, in 0-1.
+    fn normalized_from_value(&self, value: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        if self.is_logarithmic() {
+            remap_clamp(value.max(min).ln(), min.ln()..=max.ln(), 0.0..=1.0)
+        } else {
+            remap_clamp(value, min..=max, 0.0..=1.0)
+        }
+    }
+
+    /// The value at `normalized` (0-1) along the slider.
+    fn value_from_normalized(&self, normalized: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        if self.is_logarithmic() {
+            remap_clamp(normalized, 0.0..=1.0, min.ln()..=max.ln()).exp()
+        } else {
+            remap_clamp(normalized, 0.0..=1.0, min..=max)
+        }
+    }
+
+    /// Change the value with the arrow keys etc.
+    fn keyboard_input(&mut self, region: &Region) {
+        let mut value = self.get_value_f32();
+        for event in &region.input().events {
+            let (key, pressed) = match event {
+                Event::Key { key, pressed } => (key, *pressed),
+                _ => continue,
+            };
+            if !pressed {
+                continue;
+            }
+            let steps = match key {
+                Key::Left | Key::Down => -1.0,
+                Key::Right | Key::Up => 1.0,
+                Key::PageDown => -10.0,
+                Key::PageUp => 10.0,
+                Key::Home => {
+                    value = *self.range.start();
+                    continue;
+                }
+                Key::End => {
+                    value = *self.range.end();
+                    continue;
+                }
+                _ => continue,
+            };
+            value = match self.step {
+                Some(step) => value + steps * step,
+                None => {
+                    let normalized = self.normalized_from_value(value) + steps * 0.01;
+                    self.value_from_normalized(normalized)
+                }
+            };
+        }
+        if value != self.get_value_f32() {
+            self.set_value_f32(value);
+        }
+    }
+
+    /// "text: value", where you can click the value to type in a new one.
+    fn text_ui(&mut self, region: &mut Region, id: Id) {
+        let text = self.text.clone().unwrap_or_default();
+        region.horizontal(Align::Center, |region| {
+            region.add(
+                Label::new(format!("{}: ", text))
+                    .multiline(false)
+                    .text_color(
+                        self.text_color
+                            .unwrap_or_else(|| region.style().text_color()),
+                    ),
+            );
+
+            let value_id_source = (id, "value");
+            let value_id = Id::new(value_id_source);
+            if self.enabled && region.has_kb_focus(value_id) {
+                // Typed values are snapped to `step` just like dragged ones:
+                let old_value = self.get_value_f32();
+                let mut value = old_value;
+                let range = self.range.clone();
+                region.add(
+                    DragValue::f32(&mut value)
+                        .clamp_range((*range.start() as f64)..=(*range.end() as f64))
+                        .precision(self.precision)
+                        .id(value_id_source),
+                );
+                if value != old_value {
+                    self.set_value_f32(value);
+                }
+            } else {
+                // If we lost keyboard focus while editing, the `DragValue` wasn't shown to commit it:
+                let edit_text = drag_value::take_edit_text(&mut region.memory(), value_id);
+                if let Some(typed_value) = edit_text.and_then(|text| text.trim().parse().ok()) {
+                    self.set_value_f32(typed_value);
+                }
+
+                let value = self.get_value_f32();
+                let text_style = TextStyle::Button;
+                let font = &region.fonts()[text_style];
+                let (value_text, size) =
+                    font.layout_single_line(&format!("{:.*}", self.precision, value));
+                let interaction_id = if self.enabled { Some(value_id) } else { None };
+                let interact = region.reserve_space(size, interaction_id);
+                if interact.hovered && self.enabled {
+                    region.output().cursor_icon = CursorIcon::Text;
+                }
+                if interact.clicked {
+                    region.request_kb_focus(value_id);
+                }
+                let look = enabled_look(&interact, self.enabled);
+                let text_color = self
+                    .text_color
+                    .unwrap_or_else(|| enabled_stroke_color(region.style(), &look, self.enabled));
+                region.add_text(interact.rect.min, text_style, value_text, Some(text_color));
+            }
+        });
+    }
+
+    /// The slider itself, without any text.
+    fn slider_ui(&mut self, region: &mut Region, id: Id) -> GuiResponse {
+        let vertical = self.orientation == Direction::Vertical;
+        let font = &region.fonts()[TextStyle::Button];
+        let thickness = font.line_spacing().max(region.style().clickable_diameter);
+        let handle_radius = thickness / 2.5;
+
+        let size = if vertical {
+            let length = 5.0 * region.style().clickable_diameter; // TODO: style
+            vec2(thickness, region.available_height().min(length))
+        } else {
+            vec2(region.available_width(), thickness)
+        };
+        let interact = region.reserve_space(size, if self.enabled { Some(id) } else { None });
+        let rect = interact.rect;
+
+        // Where the center of the handle goes for the smallest and largest values:
+        let (start, end) = if vertical {
+            (rect.bottom() - handle_radius, rect.top() + handle_radius)
+        } else {
+            (rect.left() + handle_radius, rect.right() - handle_radius)
+        };
+
+        debug_assert!(self.range.start() <= self.range.end());
+
+        if interact.active {
+            region.request_kb_focus(id);
+            if let Some(mouse_pos) = region.input().mouse_pos {
+                let mouse = if vertical { mouse_pos.y } else { mouse_pos.x };
+                let normalized = remap_clamp(mouse, start..=end, 0.0..=1.0);
+                let value = self.value_from_normalized(normalized);
+                self.set_value_f32(value);
+            }
+        }
+
+        // Clicking outside of us gives up the keyboard:
+        if region.has_kb_focus(id) && region.input().mouse_pressed && !interact.hovered {
+            region.memory().kb_focus_id = None;
+        }
+        let has_kb_focus = self.enabled && region.has_kb_focus(id);
+        if has_kb_focus {
+            self.keyboard_input(region);
+        }
+
+        // Paint it:
+        {
+            let value = self.get_value_f32();
+
+            let rail_radius = region.round_to_pixel((thickness / 8.0).max(2.0));
+            let rail_rect = if vertical {
+                Rect::from_min_max(
+                    pos2(rect.center().x - rail_radius, rect.top()),
+                    pos2(rect.center().x + rail_radius, rect.bottom()),
+                )
+            } else {
+                Rect::from_min_max(
+                    pos2(rect.left(), rect.center().y - rail_radius),
+                    pos2(rect.right(), rect.center().y + rail_radius),
+                )
+            };
+            let marker = lerp(start..=end, self.normalized_from_value(value));
+            let marker_center = if vertical {
+                pos2(rail_rect.center().x, marker)
+            } else {
+                pos2(marker, rail_rect.center().y)
+            };
+
+            region.add_paint_cmd(PaintCmd::Rect {
+                rect: rail_rect,
+                corner_radius: rail_radius,
+                fill_color: Some(region.style().background_fill_color()),
+                outline: Some(Outline::new(1.0, color::gray(200, 255))), // TODO
+            });
+
+            let look = InteractInfo {
+                hovered: (interact.hovered && self.enabled) || has_kb_focus,
+                ..interact
+            };
+            region.add_paint_cmd(PaintCmd::Circle {
+                center: marker_center,
+                radius: handle_radius,
+                fill_color: region.style().interact_fill_color(&look),
+                outline: Some(Outline::new(
+                    region.style().interact_stroke_width(&look),
+                    enabled_stroke_color(region.style(), &look, self.enabled),
+                )),
+            });
+        }
+
+        region.response(interact)
+    }
 }
 
 impl<'a> Widget for Slider<'a> {
     fn ui(mut self, region: &mut Region) -> GuiResponse {
-        let text_style = TextStyle::Button;
-        let font = &region.fonts()[text_style];
-
         if let Some(text) = &self.text {
             if self.id.is_none() {
                 self.id = Some(Id::new(text));
             }
+        }
+        let id = self.id.unwrap_or_else(|| region.make_position_id());
+
+        if self.text.is_none() {
+            return self.slider_ui(region, id);
+        }
 
-            let text_on_top = self.text_on_top.unwrap_or_default();
-            let text_color = self.text_color;
-            let value = (self.get_set_value)(None);
-            let full_text = format!("{}: {:.*}", text, self.precision, value);
+        let text_on_top = self.text_on_top.unwrap_or_default();
+        if text_on_top || self.orientation == Direction::Vertical {
+            self.text_ui(region, id);
+            self.slider_ui(region, id)
+        } else {
+            region.columns(2, |columns| {
+                // Slider on the left:
+                let slider_response = self.slider_ui(&mut columns[0], id);
 
-            let slider_sans_text = Slider { text: None, ..self };
+                // Place the text in line with the slider on the left:
+                columns[1]
+                    .desired_rect
+                    .set_height(slider_response.rect.height());
+                self.text_ui(&mut columns[1], id);
 
-            if text_on_top {
-                // let (text, text_size) = font.layout_multiline(&full_text, region.available_width());
-                let (text, text_size) = font.layout_single_line(&full_text);
-                let pos = region.reserve_space(text_size, None).rect.min;
-                region.add_text(pos, text_style, text, text_color);
-                slider_sans_text.ui(region)
-            } else {
-                region.columns(2, |columns| {
-                    // Slider on the left:
-                    let slider_response = columns[0].add(slider_sans_text);
-
-                    // Place the text in line with the slider on the left:
-                    columns[1]
-                        .desired_rect
-                        .set_height(slider_response.rect.height());
-                    columns[1].horizontal(Align::Center, |region| {
-                        region.add(Label::new(full_text).multiline(false));
-                    });
-
-                    slider_response
-                })
+                slider_response
+            })
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type RangeSliderGetSet<'a> = Box<dyn 'a + FnMut(Option<(f32, f32)>) -> (f32, f32)>;
+
+/// A slider with two handles, for picking a min and a max value.
+/// The handles can't cross each other.
+pub struct RangeSlider<'a> {
+    get_set_value: RangeSliderGetSet<'a>,
+    range: RangeInclusive<f32>,
+    text: Option<String>,
+    precision: usize,
+    text_on_top: Option<bool>,
+    text_color: Option<Color>,
+    id: Option<Id>,
+    enabled: bool,
+}
+
+impl<'a> RangeSlider<'a> {
+    fn from_get_set(
+        range: RangeInclusive<f32>,
+        get_set_value: impl 'a + FnMut(Option<(f32, f32)>) -> (f32, f32),
+    ) -> Self {
+        RangeSlider {
+            get_set_value: Box::new(get_set_value),
+            range,
+            text: None,
+            precision: 3,
+            text_on_top: None,
+            text_color: None,
+            id: None,
+            enabled: true,
+        }
+    }
+
+    /// Edit `value` within `range`.
+    pub fn new(value: &'a mut RangeInclusive<f32>, range: RangeInclusive<f32>) -> Self {
+        Self::from_get_set(range, move |v: Option<(f32, f32)>| {
+            if let Some((min, max)) = v {
+                *value = min..=max;
             }
-        } else {
-            let height = font.line_spacing().max(region.style().clickable_diameter);
-            let handle_radius = height / 2.5;
+            (*value.start(), *value.end())
+        })
+    }
 
-            let id = self.id.unwrap_or_else(|| region.make_position_id());
+    /// Edit a `min` and `max` within `range`.
+    pub fn f32(min: &'a mut f32, max: &'a mut f32, range: RangeInclusive<f32>) -> Self {
+        Self::from_get_set(range, move |v: Option<(f32, f32)>| {
+            if let Some(v) = v {
+                *min = v.0;
+                *max = v.1;
+            }
+            (*min, *max)
+        })
+    }
 
-            let interact = region.reserve_space(
-                Vec2 {
-                    x: region.available_width(),
-                    y: height,
-                },
-                Some(id),
-            );
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
 
-            let left = interact.rect.left() + handle_radius;
-            let right = interact.rect.right() - handle_radius;
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
 
-            let range = self.range.clone();
-            debug_assert!(range.start() <= range.end());
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
 
-            if let Some(mouse_pos) = region.input().mouse_pos {
-                if interact.active {
-                    self.set_value_f32(remap_clamp(mouse_pos.x, left..=right, range.clone()));
-                }
+    /// Put the text above the slider rather than to the right of it.
+    pub fn text_on_top(mut self, text_on_top: bool) -> Self {
+        self.text_on_top = Some(text_on_top);
+        self
+    }
+
+    /// Needed if the text is not unique, or there is no text and the slider moves around.
+    pub fn id(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id = Some(Id::new(id_source));
+        self
+    }
+
+    /// If false, the slider is grayed out and can't be changed, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    fn get_value(&mut self) -> (f32, f32) {
+        (self.get_set_value)(None)
+    }
+
+    fn set_value(&mut self, (mut min, mut max): (f32, f32)) {
+        if self.precision == 0 {
+            min = min.round();
+            max = max.round();
+        }
+        (self.get_set_value)(Some((min, max)));
+    }
+
+    /// "text: min - max"
+    fn text_ui(&mut self, region: &mut Region, text: &str) {
+        let (min, max) = self.get_value();
+        let full_text = format!(
+            "{}: {:.*} - {:.*}",
+            text, self.precision, min, self.precision, max
+        );
+        let text_color = self
+            .text_color
+            .unwrap_or_else(|| region.style().text_color());
+        region.horizontal(Align::Center, |region| {
+            region.add(
+                Label::new(full_text)
+                    .multiline(false)
+                    .text_color(text_color),
+            );
+        });
+    }
+
+    /// The slider itself, without any text.
+    fn slider_ui(&mut self, region: &mut Region, id: Id) -> GuiResponse {
+        let font = &region.fonts()[TextStyle::Button];
+        let height = font.line_spacing().max(region.style().clickable_diameter);
+        let handle_radius = height / 2.5;
+
+        let interact = region.reserve_space(vec2(region.available_width(), height), None);
+        let rect = interact.rect;
+        let left = rect.left() + handle_radius;
+        let right = rect.right() - handle_radius;
+
+        let range = self.range.clone();
+        debug_assert!(range.start() <= range.end());
+
+        let (min, max) = self.get_value();
+        let min_x = remap_clamp(min, range.clone(), left..=right);
+        let max_x = remap_clamp(max, range.clone(), left..=right);
+
+        // Whichever handle is closest to the mouse gets first dibs on it:
+        let min_id = id.with("min");
+        let max_id = id.with("max");
+        let mouse_x = region
+            .input()
+            .mouse_pos
+            .map_or(rect.center().x, |pos| pos.x);
+        let min_is_closer = if min_x == max_x {
+            mouse_x < min_x // Pull them apart
+        } else {
+            (mouse_x - min_x).abs() < (mouse_x - max_x).abs()
+        };
+        let (min_interact, max_interact) = if !self.enabled {
+            (interact, interact)
+        } else if min_is_closer {
+            let min_interact = region.interact_rect(&rect, min_id);
+            (min_interact, region.interact_rect(&rect, max_id))
+        } else {
+            let max_interact = region.interact_rect(&rect, max_id);
+            (region.interact_rect(&rect, min_id), max_interact)
+        };
+
+        if let Some(mouse_pos) = region.input().mouse_pos {
+            let value = remap_clamp(mouse_pos.x, left..=right, range.clone());
+            if min_interact.active {
+                self.set_value((value.min(max), max));
+            } else if max_interact.active {
+                self.set_value((min, value.max(min)));
             }
+        }
 
-            // Paint it:
-            {
-                let value = self.get_value_f32();
+        // Paint it:
+        {
+            let (min, max) = self.get_value();
+            let min_x = remap_clamp(min, range.clone(), left..=right);
+            let max_x = remap_clamp(max, range, left..=right);
 
-                let rect = interact.rect;
-                let rail_radius = region.round_to_pixel((height / 8.0).max(2.0));
-                let rail_rect = Rect::from_min_max(
-                    pos2(interact.rect.left(), rect.center().y - rail_radius),
-                    pos2(interact.rect.right(), rect.center().y + rail_radius),
-                );
-                let marker_center_x = remap_clamp(value, range, left..=right);
+            let rail_radius = region.round_to_pixel((height / 8.0).max(2.0));
+            let rail_rect = Rect::from_min_max(
+                pos2(rect.left(), rect.center().y - rail_radius),
+                pos2(rect.right(), rect.center().y + rail_radius),
+            );
+            region.add_paint_cmd(PaintCmd::Rect {
+                rect: rail_rect,
+                corner_radius: rail_radius,
+                fill_color: Some(region.style().background_fill_color()),
+                outline: Some(Outline::new(1.0, color::gray(200, 255))), // TODO
+            });
 
-                region.add_paint_cmd(PaintCmd::Rect {
-                    rect: rail_rect,
-                    corner_radius: rail_radius,
-                    fill_color: Some(region.style().background_fill_color()),
-                    outline: Some(Outline::new(1.0, color::gray(200, 255))), // TODO
-                });
+            // The selected part of the range:
+            let selected = InteractInfo {
+                active: true,
+                ..Default::default()
+            };
+            region.add_paint_cmd(PaintCmd::Rect {
+                rect: Rect::from_min_max(
+                    pos2(min_x, rail_rect.top()),
+                    pos2(max_x, rail_rect.bottom()),
+                ),
+                corner_radius: rail_radius,
+                fill_color: region.style().interact_fill_color(&selected),
+                outline: None,
+            });
 
+            let handles = [
+                (min_x, min_interact, min_is_closer),
+                (max_x, max_interact, !min_is_closer),
+            ];
+            for (x, interact, is_closer) in &handles {
+                // Only the handle that would be dragged looks hovered:
+                let look = InteractInfo {
+                    hovered: interact.hovered && *is_closer && self.enabled,
+                    ..*interact
+                };
                 region.add_paint_cmd(PaintCmd::Circle {
-                    center: pos2(marker_center_x, rail_rect.center().y),
+                    center: pos2(*x, rail_rect.center().y),
                     radius: handle_radius,
-                    fill_color: region.style().interact_fill_color(&interact),
+                    fill_color: region.style().interact_fill_color(&look),
                     outline: Some(Outline::new(
-                        region.style().interact_stroke_width(&interact),
-                        region.style().interact_stroke_color(&interact),
+                        region.style().interact_stroke_width(&look),
+                        enabled_stroke_color(region.style(), &look, self.enabled),
                     )),
                 });
             }
+        }
+
+        region.response(InteractInfo {
+            hovered: min_interact.hovered || max_interact.hovered,
+            clicked: min_interact.clicked || max_interact.clicked,
+            active: min_interact.active || max_interact.active,
+            rect,
+        })
+    }
+}
+
+impl<'a> Widget for RangeSlider<'a> {
+    fn ui(mut self, region: &mut Region) -> GuiResponse {
+        let text = match self.text.clone() {
+            Some(text) => text,
+            None => {
+                let id = self.id.unwrap_or_else(|| region.make_position_id());
+                return self.slider_ui(region, id);
+            }
+        };
+        let id = *self.id.get_or_insert_with(|| Id::new(&text));
 
-            region.response(interact)
+        if self.text_on_top.unwrap_or_default() {
+            self.text_ui(region, &text);
+            self.slider_ui(region, id)
+        } else {
+            region.columns(2, |columns| {
+                // Slider on the left:
+                let slider_response = self.slider_ui(&mut columns[0], id);
+
+                // Place the text in line with the slider on the left:
+                columns[1]
+                    .desired_rect
+                    .set_height(slider_response.rect.height());
+                self.text_ui(&mut columns[1], &text);
+
+                slider_response
+            })
         }
     }
 }
@@ -0,0 +1,355 @@
+use crate::{containers::*, mesher::WHITE_UV, widgets::*};
+
+/// The `ColorPicker` whose popup is open. There is at most one at a time.
+#[derive(Clone, Debug)]
+pub(crate) struct State {
+    id: Id,
+
+    /// Hue, saturation, value and alpha.
+    /// We keep these rather than converting from the color every frame,
+    /// so that we don't lose the hue when the saturation or value goes to zero.
+    hsva: (f32, f32, f32, f32),
+
+    /// The color `hsva` was for. If the color is changed from elsewhere, we start over.
+    color: Color,
+
+    /// The hex code in the text field.
+    hex: String,
+}
+
+/// A button showing a color, which opens a popup for editing it.
+///
+/// The popup has a saturation/value square, a hue bar, an alpha bar and a hex code field.
+pub struct ColorPicker<'a> {
+    color: &'a mut Color,
+    alpha: bool,
+    text: String,
+    id: Option<Id>,
+    enabled: bool,
+}
+
+impl<'a> ColorPicker<'a> {
+    pub fn new(color: &'a mut Color) -> Self {
+        ColorPicker {
+            color,
+            alpha: true,
+            text: String::new(),
+            id: None,
+            enabled: true,
+        }
+    }
+
+    /// Allow editing the alpha (opacity) of the color.
+    /// Default: true
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Label shown to the right of the button.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn id(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id = Some(Id::new(id_source));
+        self
+    }
+
+    /// If false, the button is grayed out and can't be opened, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<'a> Widget for ColorPicker<'a> {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let ColorPicker {
+            color,
+            alpha,
+            text,
+            id,
+            enabled,
+        } = self;
+
+        let id = match id {
+            Some(id) => region.make_child_id(id),
+            None => region.make_position_id(),
+        };
+        let popup_id = id.with("popup");
+        let style = *region.style();
+        let text_style = TextStyle::Button;
+        let font = &region.fonts()[text_style];
+        let (label, label_size) = font.layout_single_line(&text);
+        let label_width = if text.is_empty() {
+            0.0
+        } else {
+            style.item_spacing.x + label_size.x
+        };
+        let button_size = vec2(2.0 * style.clickable_diameter, style.clickable_diameter); // TODO: style
+
+        let interact = region.reserve_space(
+            vec2(button_size.x + label_width, button_size.y.max(label_size.y)),
+            if enabled { Some(id) } else { None },
+        );
+        let button_rect = Rect::from_min_size(interact.rect.min, button_size);
+
+        if interact.clicked {
+            region.memory().toggle_popup(popup_id);
+        }
+        let is_open = region.memory().is_popup_open(popup_id);
+
+        // The left half shows the opaque color, the right half how transparent it is:
+        let opaque_rect =
+            Rect::from_min_size(button_rect.min, vec2(0.5 * button_size.x, button_size.y));
+        let alpha_rect = Rect::from_min_max(
+            pos2(opaque_rect.right(), button_rect.top()),
+            button_rect.max,
+        );
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: 0.0,
+            fill_color: Some(Color { a: 255, ..*color }),
+            outline: None,
+            rect: opaque_rect,
+        });
+        region.add_paint_cmd(PaintCmd::Mesh(checkerboard_mesh(alpha_rect)));
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: 0.0,
+            fill_color: Some(*color),
+            outline: None,
+            rect: alpha_rect,
+        });
+
+        let look = InteractInfo {
+            hovered: (interact.hovered && enabled) || is_open,
+            ..interact
+        };
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: 0.0,
+            fill_color: None,
+            outline: Some(Outline::new(
+                style.interact_stroke_width(&look),
+                enabled_stroke_color(&style, &look, enabled),
+            )),
+            rect: button_rect,
+        });
+
+        if !text.is_empty() {
+            let text_cursor = pos2(
+                button_rect.right() + style.item_spacing.x,
+                button_rect.center().y - 0.5 * label_size.y,
+            );
+            let text_color = if enabled {
+                style.text_color()
+            } else {
+                style.disabled_text_color()
+            };
+            region.add_text(text_cursor, text_style, label, Some(text_color));
+        }
+
+        let anchor = region.response(InteractInfo {
+            rect: button_rect,
+            ..interact
+        });
+        let width = 8.0 * style.clickable_diameter; // TODO: style
+        Popup::new(popup_id).width(width).show(&anchor, |region| {
+            picker_ui(region, id, color, alpha);
+        });
+
+        region.response(interact)
+    }
+}
+
+/// The contents of the popup.
+fn picker_ui(region: &mut Region, id: Id, color: &mut Color, alpha: bool) {
+    let mut state = region
+        .memory()
+        .color_picker
+        .clone()
+        .filter(|state| state.id == id && state.color == *color)
+        .unwrap_or_else(|| State {
+            id,
+            hsva: color.to_hsva(),
+            color: *color,
+            hex: hex_code(*color, alpha),
+        });
+    let (mut h, mut s, mut v, mut a) = state.hsva;
+    let mut dragged = false;
+
+    let style = *region.style();
+    let width = region.available_width();
+    let bar_height = style.clickable_diameter;
+    let mouse_pos = region.input().mouse_pos;
+
+    // Saturation and value:
+    let interact = region.reserve_space(vec2(width, width), Some(id.with("sv")));
+    let rect = interact.rect;
+    if let (true, Some(mouse_pos)) = (interact.active, mouse_pos) {
+        s = remap_clamp(mouse_pos.x, rect.left()..=rect.right(), 0.0..=1.0);
+        v = remap_clamp(mouse_pos.y, rect.top()..=rect.bottom(), 1.0..=0.0);
+        dragged = true;
+    }
+    region.add_paint_cmd(PaintCmd::Mesh(gradient_mesh(rect, 16, 16, |x, y| {
+        Color::from_hsv(h, x, 1.0 - y)
+    })));
+    let marker_color = if v > 0.5 { color::BLACK } else { color::WHITE };
+    region.add_paint_cmd(PaintCmd::Circle {
+        center: pos2(
+            lerp(rect.left()..=rect.right(), s),
+            lerp(rect.bottom()..=rect.top(), v),
+        ),
+        radius: 5.0, // TODO: style
+        fill_color: None,
+        outline: Some(Outline::new(
+            style.interact_stroke_width(&interact),
+            marker_color,
+        )),
+    });
+
+    // Hue:
+    let interact = region.reserve_space(vec2(width, bar_height), Some(id.with("hue")));
+    let rect = interact.rect;
+    if let (true, Some(mouse_pos)) = (interact.active, mouse_pos) {
+        h = remap_clamp(mouse_pos.x, rect.left()..=rect.right(), 0.0..=1.0);
+        dragged = true;
+    }
+    region.add_paint_cmd(PaintCmd::Mesh(gradient_mesh(rect, 36, 1, |x, _| {
+        Color::from_hsv(x, 1.0, 1.0)
+    })));
+    bar_marker(region, rect, h, &interact);
+
+    // Alpha:
+    if alpha {
+        let interact = region.reserve_space(vec2(width, bar_height), Some(id.with("alpha")));
+        let rect = interact.rect;
+        if let (true, Some(mouse_pos)) = (interact.active, mouse_pos) {
+            a = remap_clamp(mouse_pos.x, rect.left()..=rect.right(), 0.0..=1.0);
+            dragged = true;
+        }
+        region.add_paint_cmd(PaintCmd::Mesh(checkerboard_mesh(rect)));
+        region.add_paint_cmd(PaintCmd::Mesh(gradient_mesh(rect, 1, 1, |x, _| {
+            Color::from_hsva(h, s, v, x)
+        })));
+        bar_marker(region, rect, a, &interact);
+    }
+
+    if dragged {
+        *color = Color::from_hsva(h, s, v, a);
+        state.hex = hex_code(*color, alpha);
+    }
+
+    // Hex code:
+    let hex_before = state.hex.clone();
+    region.add(TextEdit::new(&mut state.hex).id("hex"));
+    if state.hex != hex_before {
+        // Bad (e.g. half-typed) codes are ignored until they are fixed:
+        if let Some(mut new_color) = Color::from_hex(&state.hex) {
+            if !alpha {
+                new_color.a = color.a;
+            }
+            *color = new_color;
+            let (new_h, new_s, new_v, new_a) = new_color.to_hsva();
+            if new_s > 0.0 && new_v > 0.0 {
+                h = new_h;
+            }
+            s = new_s;
+            v = new_v;
+            a = new_a;
+        }
+    }
+
+    state.hsva = (h, s, v, a);
+    state.color = *color;
+    region.memory().color_picker = Some(state);
+}
+
+/// Mark the current value on the hue or alpha bar.
+fn bar_marker(region: &mut Region, rect: Rect, t: f32, interact: &InteractInfo) {
+    let x = lerp(rect.left()..=rect.right(), t);
+    region.add_paint_cmd(PaintCmd::Rect {
+        corner_radius: 2.0,
+        fill_color: None,
+        outline: Some(Outline::new(
+            region.style().interact_stroke_width(interact),
+            region.style().interact_stroke_color(interact),
+        )),
+        rect: Rect::from_center_size(pos2(x, rect.center().y), vec2(4.0, rect.height())),
+    });
+}
+
+fn hex_code(color: Color, alpha: bool) -> String {
+    if alpha {
+        color.to_hex()
+    } else {
+        Color { a: 255, ..color }.to_hex()
+    }
+}
+
+/// `rect` split into a grid of `nx` by `ny` cells, with the vertex colors given by `color_at(x, y)`,
+/// where x and y go from 0 to 1 across the rectangle.
+fn gradient_mesh(rect: Rect, nx: usize, ny: usize, color_at: impl Fn(f32, f32) -> Color) -> Mesh {
+    let mut mesh = Mesh::default();
+    for j in 0..=ny {
+        for i in 0..=nx {
+            let x = i as f32 / nx as f32;
+            let y = j as f32 / ny as f32;
+            mesh.vertices.push(Vertex {
+                pos: pos2(
+                    lerp(rect.left()..=rect.right(), x),
+                    lerp(rect.top()..=rect.bottom(), y),
+                ),
+                uv: WHITE_UV,
+                color: color_at(x, y),
+            });
+        }
+    }
+
+    let row = (nx + 1) as u32;
+    for j in 0..ny {
+        for i in 0..nx {
+            let idx = j as u32 * row + i as u32;
+            mesh.indices.extend_from_slice(&[
+                idx,
+                idx + 1,
+                idx + row,
+                idx + row,
+                idx + 1,
+                idx + row + 1,
+            ]);
+        }
+    }
+    mesh
+}
+
+/// A gray checkerboard, to show transparent colors on.
+fn checkerboard_mesh(rect: Rect) -> Mesh {
+    let square = 0.5 * rect.height();
+    let mut mesh = Mesh::default();
+    let mut y = rect.top();
+    let mut row = 0;
+    while y < rect.bottom() {
+        let mut x = rect.left();
+        let mut column = 0;
+        while x < rect.right() {
+            let color = if (row + column) % 2 == 0 {
+                color::gray(128, 255)
+            } else {
+                color::gray(192, 255)
+            };
+            let vertex = |pos| Vertex {
+                pos,
+                uv: WHITE_UV,
+                color,
+            };
+            let max = pos2(x + square, y + square).min(rect.max);
+            mesh.add_rect(vertex(pos2(x, y)), vertex(max));
+            x += square;
+            column += 1;
+        }
+        y += square;
+        row += 1;
+    }
+    mesh
+}
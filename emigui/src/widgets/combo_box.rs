@@ -0,0 +1,350 @@
+use crate::{containers::*, widgets::enabled_stroke_color, *};
+
+/// Remembers the keyboard highlight and what the user has typed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct State {
+    /// The item highlighted by the mouse, the arrow keys or by searching.
+    highlighted: Option<usize>,
+
+    /// Scroll the highlighted item into view.
+    scroll_to_highlighted: bool,
+
+    /// What the user has typed so far, to jump to the first item starting with it.
+    search: String,
+
+    /// When the user last typed something. After a pause, the search starts over.
+    last_search_time: f64,
+}
+
+/// A button showing the selected value, which opens a list of values to choose from.
+///
+/// Usage:
+/// ```ignore
+/// region.add(
+///     ComboBox::new(&mut self.mode)
+///         .item(Mode::Fast, "Fast")
+///         .item(Mode::Slow, "Slow")
+///         .text("Mode"),
+/// );
+/// ```
+pub struct ComboBox<'a, T: PartialEq> {
+    selected: &'a mut T,
+    items: Vec<(T, String)>,
+    text: String,
+    id: Option<Id>,
+    width: Option<f32>,
+    text_color: Option<Color>,
+    enabled: bool,
+}
+
+impl<'a, T: PartialEq> ComboBox<'a, T> {
+    pub fn new(selected: &'a mut T) -> Self {
+        ComboBox {
+            selected,
+            items: vec![],
+            text: String::new(),
+            id: None,
+            width: None,
+            text_color: None,
+            enabled: true,
+        }
+    }
+
+    /// Add a value to choose from, with the text to show for it.
+    pub fn item(mut self, value: T, text: impl Into<String>) -> Self {
+        self.items.push((value, text.into()));
+        self
+    }
+
+    /// Label shown to the right of the button.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn id(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id = Some(Id::new(id_source));
+        self
+    }
+
+    /// Width of the button and the list.
+    /// Default: wide enough for the widest item.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// If false, the combo box is grayed out and can't be opened, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<'a, T: PartialEq> Widget for ComboBox<'a, T> {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let ComboBox {
+            selected,
+            items,
+            text,
+            id,
+            width,
+            text_color,
+            enabled,
+        } = self;
+
+        let id = match id {
+            Some(id) => region.make_child_id(id),
+            None => region.make_position_id(),
+        };
+        let popup_id = id.with("popup");
+        let style = *region.style();
+        let text_style = TextStyle::Button;
+        let font = &region.fonts()[text_style];
+        let padding = style.button_padding;
+        let arrow_width = style.start_icon_width;
+
+        let button_width = width.unwrap_or_else(|| {
+            let widest_item = items
+                .iter()
+                .map(|(_, item_text)| font.layout_single_line(item_text).1.x)
+                .fold(0.0, f32::max);
+            padding.x + widest_item + arrow_width + padding.x
+        });
+        let selected_index = items.iter().position(|(value, _)| value == selected);
+        let selected_text = selected_index.map_or("", |i| items[i].1.as_str());
+        let (selected_text, selected_size) = font.layout_single_line(selected_text);
+        let (label, label_size) = font.layout_single_line(&text);
+        let label_width = if text.is_empty() {
+            0.0
+        } else {
+            style.item_spacing.x + label_size.x
+        };
+        let button_height = (selected_size.y + 2.0 * padding.y).max(style.clickable_diameter);
+
+        let interaction_id = if enabled { Some(id) } else { None };
+        let interact = region.reserve_space(
+            vec2(button_width + label_width, button_height),
+            interaction_id,
+        );
+        let button_rect = Rect::from_min_size(interact.rect.min, vec2(button_width, button_height));
+
+        if interact.clicked {
+            region.memory().toggle_popup(popup_id);
+        }
+        let is_open = region.memory().is_popup_open(popup_id);
+
+        // We want the keys while our list is open, and only then:
+        if is_open && interact.clicked {
+            region.request_kb_focus(id);
+        } else if !is_open && region.has_kb_focus(id) {
+            region.memory().kb_focus_id = None;
+        }
+
+        let mut state = region
+            .memory()
+            .combo_boxes
+            .get(&id)
+            .cloned()
+            .unwrap_or_default();
+        if !is_open {
+            state.highlighted = selected_index;
+        }
+        if interact.clicked {
+            state.scroll_to_highlighted = true;
+        }
+        let mut new_selection = None;
+
+        if is_open && region.has_kb_focus(id) {
+            let time = region.input().time;
+            for event in &region.input().events {
+                match event {
+                    Event::Key {
+                        key: Key::Return,
+                        pressed: true,
+                    } => {
+                        new_selection = state.highlighted;
+                    }
+                    Event::Key { key, pressed: true } => {
+                        if let Some(index) = step(state.highlighted, key, items.len()) {
+                            state.highlighted = Some(index);
+                            state.scroll_to_highlighted = true;
+                        }
+                    }
+                    Event::Text(typed) => {
+                        if time - state.last_search_time > 1.0 {
+                            state.search.clear();
+                        }
+                        state.last_search_time = time;
+                        state
+                            .search
+                            .extend(typed.chars().filter(|c| !c.is_control()));
+                        let search = state.search.to_lowercase();
+                        let found = items.iter().position(|(_, item_text)| {
+                            item_text.to_lowercase().starts_with(&search)
+                        });
+                        if let Some(index) = found {
+                            state.highlighted = Some(index);
+                            state.scroll_to_highlighted = true;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let look = InteractInfo {
+            hovered: (interact.hovered && enabled) || is_open,
+            ..interact
+        };
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: style.interact_corner_radius(&look),
+            fill_color: style.interact_fill_color(&look),
+            outline: style.interact_outline(&look),
+            rect: button_rect,
+        });
+
+        let stroke_color = enabled_stroke_color(&style, &look, enabled);
+        let mut text_cursor = button_rect.left_center() + vec2(padding.x, -0.5 * selected_size.y);
+        text_cursor.y += 2.0; // TODO: why is this needed?
+        region.add_text(
+            text_cursor,
+            text_style,
+            selected_text,
+            Some(text_color.unwrap_or(stroke_color)),
+        );
+
+        // Paint a down arrow:
+        let arrow_rect = Rect::from_min_max(
+            pos2(button_rect.right() - arrow_width, button_rect.top()),
+            button_rect.max,
+        );
+        let (small_icon_rect, _) = style.icon_rectangles(&arrow_rect);
+        region.add_paint_cmd(PaintCmd::Line {
+            points: vec![
+                pos2(small_icon_rect.left(), small_icon_rect.center().y - 2.5),
+                pos2(small_icon_rect.center().x, small_icon_rect.center().y + 2.5),
+                pos2(small_icon_rect.right(), small_icon_rect.center().y - 2.5),
+            ],
+            color: stroke_color,
+            width: style.interact_stroke_width(&look),
+        });
+
+        if !text.is_empty() {
+            text_cursor.x = button_rect.right() + style.item_spacing.x;
+            region.add_text(
+                text_cursor,
+                text_style,
+                label,
+                Some(text_color.unwrap_or_else(|| {
+                    if enabled {
+                        style.text_color()
+                    } else {
+                        style.disabled_text_color()
+                    }
+                })),
+            );
+        }
+
+        let anchor = region.response(InteractInfo {
+            rect: button_rect,
+            ..interact
+        });
+        if new_selection.is_none() {
+            Popup::new(popup_id)
+                .width(button_width)
+                .show(&anchor, |region| {
+                    ScrollArea::default().show(region, |region| {
+                        new_selection = list_ui(region, popup_id, &items, selected, &mut state);
+                    });
+                });
+        }
+
+        if let Some(index) = new_selection {
+            region.memory().close_popup(popup_id);
+            state.highlighted = Some(index);
+            if let Some((value, _)) = items.into_iter().nth(index) {
+                *selected = value;
+            }
+        }
+        region.memory().combo_boxes.insert(id, state);
+
+        region.response(interact)
+    }
+}
+
+/// The list in the popup. Returns the index of the clicked item, if any.
+fn list_ui<T: PartialEq>(
+    region: &mut Region,
+    popup_id: Id,
+    items: &[(T, String)],
+    selected: &T,
+    state: &mut State,
+) -> Option<usize> {
+    let text_style = TextStyle::Button;
+    let padding = region.style().button_padding;
+    let mouse_moved = region.input().mouse_move != Vec2::zero();
+    let mut clicked = None;
+
+    for (i, (value, item_text)) in items.iter().enumerate() {
+        let font = &region.fonts()[text_style];
+        let (text, text_size) = font.layout_single_line(item_text);
+        let size = vec2(region.available_width(), text_size.y + 2.0 * padding.y);
+        let interact = region.reserve_space(size, Some(popup_id.with(i)));
+
+        if interact.hovered && mouse_moved {
+            state.highlighted = Some(i);
+        }
+        if interact.clicked {
+            clicked = Some(i);
+        }
+
+        let highlighted = state.highlighted == Some(i);
+        if highlighted || value == selected {
+            let look = InteractInfo {
+                hovered: highlighted,
+                ..interact
+            };
+            region.add_paint_cmd(PaintCmd::Rect {
+                corner_radius: 0.0,
+                fill_color: region.style().interact_fill_color(&look),
+                outline: None,
+                rect: interact.rect,
+            });
+        }
+
+        let text_cursor = interact.rect.min + padding;
+        let text_color = region.style().interact_stroke_color(&interact);
+        region.add_text(text_cursor, text_style, text, Some(text_color));
+
+        if highlighted && state.scroll_to_highlighted {
+            region.response(interact).scroll_to_me(Align::Center);
+            state.scroll_to_highlighted = false;
+        }
+    }
+
+    clicked
+}
+
+/// Move the highlight with the arrow keys etc.
+fn step(index: Option<usize>, key: &Key, num_items: usize) -> Option<usize> {
+    if num_items == 0 {
+        return None;
+    }
+    let page = 10;
+    let last = num_items - 1;
+    match (key, index) {
+        (Key::Down, None) | (Key::Home, _) => Some(0),
+        (Key::Up, None) | (Key::End, _) => Some(last),
+        (Key::Down, Some(i)) => Some((i + 1).min(last)),
+        (Key::Up, Some(i)) => Some(i.saturating_sub(1)),
+        (Key::PageDown, i) => Some(i.map_or(0, |i| (i + page).min(last))),
+        (Key::PageUp, i) => Some(i.map_or(0, |i| i.saturating_sub(page))),
+        _ => None,
+    }
+}
@@ -0,0 +1,376 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    widgets::{enabled_look, enabled_stroke_color},
+    *,
+};
+
+/// Kept per `DragValue`, so that one being edited keeps its text while another one is clicked.
+#[derive(Clone, Debug)]
+pub(crate) struct State {
+    /// The unrounded and unclamped value while dragging,
+    /// so that slow drags of integers still add up.
+    drag_value: f64,
+
+    /// How far the mouse has moved since it was pressed, in points.
+    /// If it is small, it was a click rather than a drag.
+    drag_distance: f32,
+
+    /// When we were last clicked. Used for detecting double-clicks.
+    last_click_time: f64,
+
+    /// The text being edited, when in text input mode.
+    edit_text: Option<String>,
+}
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type DragValueGetSet<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+/// A number you can change by dragging it left or right.
+/// Hold down shift for finer adjustment.
+/// Double-click it (or give it keyboard focus) to type in a number.
+pub struct DragValue<'a> {
+    get_set_value: DragValueGetSet<'a>,
+    speed: f64,
+    range: RangeInclusive<f64>,
+    prefix: String,
+    suffix: String,
+    precision: usize,
+    text_color: Option<Color>,
+    id: Option<Id>,
+    enabled: bool,
+}
+
+impl<'a> DragValue<'a> {
+    pub(crate) fn from_get_set(get_set_value: impl 'a + FnMut(Option<f64>) -> f64) -> Self {
+        DragValue {
+            get_set_value: Box::new(get_set_value),
+            speed: 1.0,
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            prefix: String::new(),
+            suffix: String::new(),
+            precision: 3,
+            text_color: None,
+            id: None,
+            enabled: true,
+        }
+    }
+
+    pub fn f32(value: &'a mut f32) -> Self {
+        DragValue {
+            speed: 0.1,
+            ..Self::from_get_set(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = v as f32
+                }
+                *value as f64
+            })
+        }
+    }
+
+    pub fn f64(value: &'a mut f64) -> Self {
+        DragValue {
+            speed: 0.1,
+            ..Self::from_get_set(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = v
+                }
+                *value
+            })
+        }
+    }
+
+    pub fn i32(value: &'a mut i32) -> Self {
+        DragValue {
+            precision: 0,
+            ..Self::from_get_set(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = v.round() as i32
+                }
+                *value as f64
+            })
+        }
+    }
+
+    pub fn usize(value: &'a mut usize) -> Self {
+        DragValue {
+            precision: 0,
+            range: 0.0..=f64::INFINITY,
+            ..Self::from_get_set(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = v.round() as usize
+                }
+                *value as f64
+            })
+        }
+    }
+
+    /// How much the value changes per point the mouse is dragged.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Keep the value within this range, both when dragging and typing.
+    pub fn clamp_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Shown before the value, e.g. "x: "
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Shown after the value, e.g. a unit like " m/s"
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Number of decimals shown
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    pub fn id(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id = Some(Id::new(id_source));
+        self
+    }
+
+    /// If false, the value is grayed out and can't be dragged or edited, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    fn get_value(&mut self) -> f64 {
+        (self.get_set_value)(None)
+    }
+
+    fn set_value(&mut self, mut value: f64) {
+        if self.precision == 0 {
+            value = value.round();
+        }
+        let value = value.max(*self.range.start()).min(*self.range.end());
+        (self.get_set_value)(Some(value));
+    }
+
+    /// Set the value from typed text. Bad input is ignored.
+    fn set_value_from_text(&mut self, text: &str) {
+        if let Ok(value) = text.trim().parse::<f64>() {
+            self.set_value(value);
+        }
+    }
+}
+
+/// Forget the `DragValue` with this id, returning the text being typed into it, if any.
+/// For when the `DragValue` is no longer shown, and so can't use the text itself.
+pub(crate) fn take_edit_text(memory: &mut Memory, id: Id) -> Option<String> {
+    memory
+        .drag_values
+        .remove(&id)
+        .and_then(|state| state.edit_text)
+}
+
+impl<'a> Widget for DragValue<'a> {
+    fn ui(mut self, region: &mut Region) -> GuiResponse {
+        let id = self.id.unwrap_or_else(|| region.make_position_id());
+        let value = self.get_value();
+        let time = region.input().time;
+        let double_click_time = 0.3; // TODO: style
+
+        let mut state = region.memory().drag_values.get(&id).cloned();
+
+        // Text input mode:
+        let mut has_kb_focus = self.enabled && region.has_kb_focus(id);
+        match (&mut state, has_kb_focus) {
+            (Some(state), true) => {
+                if state.edit_text.is_none() {
+                    state.edit_text = Some(format!("{:.*}", self.precision, value));
+                }
+            }
+            (None, true) => {
+                state = Some(State {
+                    drag_value: value,
+                    drag_distance: 0.0,
+                    last_click_time: f64::NEG_INFINITY,
+                    edit_text: Some(format!("{:.*}", self.precision, value)),
+                });
+            }
+            (Some(state), false) => {
+                // We lost keyboard focus, e.g. because the user clicked another text field.
+                if let Some(edit_text) = state.edit_text.take() {
+                    self.set_value_from_text(&edit_text);
+                }
+            }
+            (None, false) => {}
+        }
+
+        if has_kb_focus {
+            let mut done = false;
+            let mut used_escape = false;
+            let edit_text = state.as_mut().and_then(|state| state.edit_text.as_mut());
+            if let Some(edit_text) = edit_text {
+                for event in &region.input().events {
+                    match event {
+                        Event::Text(text) => {
+                            edit_text.extend(text.chars().filter(|c| !c.is_control()));
+                        }
+                        Event::Key {
+                            key: Key::Backspace,
+                            pressed: true,
+                        } => {
+                            edit_text.pop();
+                        }
+                        Event::Key {
+                            key: Key::Return,
+                            pressed: true,
+                        } => {
+                            done = true;
+                        }
+                        Event::Key {
+                            key: Key::Escape,
+                            pressed: true,
+                        } => {
+                            edit_text.clear(); // Don't change the value
+                            done = true;
+                            used_escape = true;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if done {
+                if let Some(edit_text) = state.as_mut().and_then(|state| state.edit_text.take()) {
+                    self.set_value_from_text(&edit_text);
+                }
+                let mut memory = region.memory();
+                memory.kb_focus_id = None;
+                memory.used_escape |= used_escape;
+                has_kb_focus = false;
+            }
+        }
+
+        let value = self.get_value();
+        let text = match state.as_ref().and_then(|state| state.edit_text.as_ref()) {
+            Some(edit_text) => edit_text.clone(),
+            None => format!("{}{:.*}{}", self.prefix, self.precision, value, self.suffix),
+        };
+
+        let text_style = TextStyle::Button;
+        let font = &region.fonts()[text_style];
+        let (text, text_size) = font.layout_single_line(&text);
+        let padding = region.style().button_padding;
+        let mut size = text_size + 2.0 * padding;
+        size.x = size.x.max(region.style().clickable_diameter);
+        size.y = size.y.max(region.style().clickable_diameter);
+        let interact = region.reserve_space(size, if self.enabled { Some(id) } else { None });
+
+        if has_kb_focus {
+            // Clicking outside of us ends text input:
+            if region.input().mouse_pressed && !interact.hovered {
+                if let Some(edit_text) = state.as_mut().and_then(|state| state.edit_text.take()) {
+                    self.set_value_from_text(&edit_text);
+                }
+                region.memory().kb_focus_id = None;
+                has_kb_focus = false;
+            }
+        } else {
+            if (interact.hovered && self.enabled) || interact.active {
+                region.output().cursor_icon = CursorIcon::ResizeHorizontal;
+            }
+
+            if interact.active {
+                if region.input().mouse_pressed {
+                    let last_click_time = state
+                        .as_ref()
+                        .map_or(f64::NEG_INFINITY, |state| state.last_click_time);
+                    state = Some(State {
+                        drag_value: self.get_value(),
+                        drag_distance: 0.0,
+                        last_click_time,
+                        edit_text: None,
+                    });
+                } else if let Some(state) = &mut state {
+                    let mut speed = self.speed;
+                    if region.input().modifiers.shift {
+                        speed *= 0.1; // Fine adjustment
+                    }
+                    let mouse_move = region.input().mouse_move;
+                    state.drag_distance += mouse_move.length();
+                    state.drag_value += mouse_move.x as f64 * speed;
+                    self.set_value(state.drag_value);
+                }
+            }
+
+            if interact.clicked {
+                if let Some(state) = &mut state {
+                    if state.drag_distance < 2.0 {
+                        if time - state.last_click_time < double_click_time {
+                            state.edit_text = Some(format!("{:.*}", self.precision, value));
+                            region.request_kb_focus(id);
+                        }
+                        state.last_click_time = time;
+                    }
+                }
+            }
+        }
+
+        // Paint it:
+        let look = if has_kb_focus {
+            InteractInfo {
+                active: true,
+                ..interact
+            }
+        } else {
+            enabled_look(&interact, self.enabled)
+        };
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: region.style().interact_corner_radius(&look),
+            fill_color: region.style().interact_fill_color(&look),
+            outline: region.style().interact_outline(&look),
+            rect: interact.rect,
+        });
+
+        let text_pos = pos2(
+            interact.rect.center().x - 0.5 * text_size.x,
+            interact.rect.center().y - 0.5 * text_size.y,
+        );
+        let text_color = self
+            .text_color
+            .unwrap_or_else(|| enabled_stroke_color(region.style(), &look, self.enabled));
+
+        if has_kb_focus {
+            let cursor_blink_hz = region.style().cursor_blink_hz;
+            let show_cursor = (time * cursor_blink_hz as f64 * 3.0).floor() as i64 % 3 != 0;
+            if show_cursor {
+                let cursor_x = text_pos.x + text.last().map_or(0.0, |last| last.max_x());
+                region.add_paint_cmd(PaintCmd::line_segment(
+                    (
+                        pos2(cursor_x, text_pos.y),
+                        pos2(cursor_x, text_pos.y + text_size.y),
+                    ),
+                    text_color,
+                    region.style().text_cursor_width,
+                ));
+            }
+        }
+
+        region.add_text(text_pos, text_style, text, Some(text_color));
+
+        if let Some(state) = state {
+            region.memory().drag_values.insert(id, state);
+        }
+
+        region.response(interact)
+    }
+}
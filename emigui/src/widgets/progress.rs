@@ -0,0 +1,160 @@
+use crate::{mesher::Path, *};
+
+/// A bar showing how far along something is.
+///
+/// If you don't know, use `ProgressBar::indeterminate()` to show that something is going on.
+pub struct ProgressBar {
+    /// `None` means indeterminate.
+    progress: Option<f32>,
+    text: Option<String>,
+    width: Option<f32>,
+}
+
+impl ProgressBar {
+    /// `progress` goes from 0 (nothing done) to 1 (all done).
+    pub fn new(progress: f32) -> Self {
+        ProgressBar {
+            progress: Some(clamp(progress, 0.0..=1.0)),
+            text: None,
+            width: None,
+        }
+    }
+
+    /// A bar with a segment moving back and forth, for when we don't know how long something will take.
+    pub fn indeterminate() -> Self {
+        ProgressBar {
+            progress: None,
+            text: None,
+            width: None,
+        }
+    }
+
+    /// Shown on top of the bar, e.g. "42 %" or "Downloading…"
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Default: all the available width.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+impl Widget for ProgressBar {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let style = *region.style();
+        let width = self.width.unwrap_or_else(|| region.available_width());
+        let height = style.clickable_diameter;
+        let interact = region.reserve_space(vec2(width, height), None);
+        let rect = interact.rect;
+        let corner_radius = 0.25 * height; // TODO: style
+
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius,
+            fill_color: Some(style.inactive_fill_color),
+            outline: None,
+            rect,
+        });
+
+        let bar_rect = match self.progress {
+            Some(progress) => {
+                Rect::from_min_size(rect.min, vec2(progress * rect.width(), rect.height()))
+            }
+            None => {
+                // A quarter of the bar sliding from left to right, over and over:
+                let period = 1.5; // TODO: style
+                let t = (region.input().time / period).fract() as f32;
+                let segment_width = 0.25 * rect.width();
+                let left = lerp(rect.left() - segment_width..=rect.right(), t);
+                region.ctx().request_repaint();
+                Rect::from_min_max(
+                    pos2(left.max(rect.left()), rect.top()),
+                    pos2((left + segment_width).min(rect.right()), rect.bottom()),
+                )
+            }
+        };
+        if bar_rect.width() > 0.0 {
+            region.add_paint_cmd(PaintCmd::Rect {
+                corner_radius,
+                fill_color: Some(style.active_fill_color),
+                outline: None,
+                rect: bar_rect,
+            });
+        }
+
+        if let Some(text) = self.text {
+            let text_style = TextStyle::Button;
+            let font = &region.fonts()[text_style];
+            let (text, text_size) = font.layout_single_line(&text);
+            let text_pos = rect.center() - 0.5 * text_size;
+            region.add_text(text_pos, text_style, text, Some(style.text_color()));
+        }
+
+        region.response(interact)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A spinning arc, to show that something is going on.
+pub struct Spinner {
+    size: Option<f32>,
+    color: Option<Color>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Spinner {
+            size: None,
+            color: None,
+        }
+    }
+
+    /// Width and height. Default: `Style::clickable_diameter`
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Widget for Spinner {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let style = *region.style();
+        let size = self.size.unwrap_or(style.clickable_diameter);
+        let interact = region.reserve_space(vec2(size, size), None);
+        region.ctx().request_repaint();
+
+        let line_width = 2.0 * style.line_width; // TODO: style
+        let radius = 0.5 * size - line_width;
+        let time = region.input().time;
+        // One turn per second, with the arc growing and shrinking as it goes:
+        let start_angle = TAU * time.fract() as f32;
+        let arc_length = TAU * (0.3 + 0.25 * (3.0 * time).sin() as f32);
+
+        let mut path = Path::default();
+        path.add_arc(
+            interact.rect.center(),
+            radius,
+            start_angle,
+            start_angle + arc_length,
+        );
+        region.add_paint_cmd(PaintCmd::Path {
+            path,
+            closed: false,
+            fill_color: None,
+            outline: Some(Outline::new(
+                line_width,
+                self.color.unwrap_or_else(|| style.text_color()),
+            )),
+        });
+
+        region.response(interact)
+    }
+}
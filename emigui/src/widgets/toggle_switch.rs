@@ -0,0 +1,113 @@
+use crate::{
+    widgets::{enabled_look, enabled_stroke_color},
+    *,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct State {
+    /// Where the knob is: 0 = off (left), 1 = on (right).
+    /// Moves towards `checked` over `Style::animation_time`.
+    knob: f32,
+}
+
+/// An on/off switch with a knob that slides over when clicked.
+/// Does the same as a `Checkbox`, but looks like a light switch.
+#[derive(Debug)]
+pub struct ToggleSwitch<'a> {
+    checked: &'a mut bool,
+    text: String,
+    text_color: Option<Color>,
+    enabled: bool,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(checked: &'a mut bool, text: impl Into<String>) -> Self {
+        ToggleSwitch {
+            checked,
+            text: text.into(),
+            text_color: None,
+            enabled: true,
+        }
+    }
+
+    pub fn text_color(mut self, text_color: Color) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// If false, the switch is grayed out and can't be flipped, but still shows tooltips.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<'a> Widget for ToggleSwitch<'a> {
+    fn ui(self, region: &mut Region) -> GuiResponse {
+        let id = region.make_position_id();
+        let style = *region.style();
+        let text_style = TextStyle::Button;
+        let font = &region.fonts()[text_style];
+        let (text, text_size) = font.layout_multiline(&self.text, region.available_width());
+        // Twice as wide as the icon of a checkbox:
+        let switch_width = 2.0 * style.start_icon_width;
+        let interact = region.reserve_space(
+            style.button_padding + vec2(switch_width, 0.0) + text_size + style.button_padding,
+            if self.enabled { Some(id) } else { None },
+        );
+        let text_cursor = interact.rect.min + style.button_padding + vec2(switch_width, 0.0);
+        if interact.clicked {
+            *self.checked = !*self.checked;
+        }
+
+        let target = if *self.checked { 1.0 } else { 0.0 };
+        let mut state = region
+            .memory()
+            .toggle_switches
+            .get(&id)
+            .copied()
+            .unwrap_or(State { knob: target });
+        if state.knob != target {
+            let step = region.input().dt / style.animation_time.max(1e-6);
+            state.knob = if state.knob < target {
+                (state.knob + step).min(target)
+            } else {
+                (state.knob - step).max(target)
+            };
+            region.ctx().request_repaint();
+        }
+        region.memory().toggle_switches.insert(id, state);
+
+        let (small_icon_rect, big_icon_rect) = style.icon_rectangles(&interact.rect);
+        let track_rect = Rect::from_min_size(
+            big_icon_rect.min,
+            vec2(switch_width, big_icon_rect.height()),
+        );
+        let enabled_interact = enabled_look(&interact, self.enabled);
+        let look = InteractInfo {
+            active: interact.active || *self.checked,
+            ..enabled_interact
+        };
+        region.add_paint_cmd(PaintCmd::Rect {
+            corner_radius: 0.5 * track_rect.height(),
+            fill_color: style.interact_fill_color(&look),
+            outline: None,
+            rect: track_rect,
+        });
+
+        let stroke_color = enabled_stroke_color(&style, &enabled_interact, self.enabled);
+        let knob_radius = 0.5 * small_icon_rect.height();
+        let knob_travel = (track_rect.left() + 0.5 * track_rect.height())
+            ..=(track_rect.right() - 0.5 * track_rect.height());
+        region.add_paint_cmd(PaintCmd::Circle {
+            center: pos2(lerp(knob_travel, state.knob), track_rect.center().y),
+            radius: knob_radius,
+            fill_color: Some(stroke_color),
+            outline: None,
+        });
+
+        let text_color = self.text_color.unwrap_or(stroke_color);
+        region.add_text(text_cursor, text_style, text, Some(text_color));
+        region.response(interact)
+    }
+}
@@ -37,8 +37,18 @@ pub fn input_event(
             Resized(glutin::dpi::LogicalSize { width, height }) => {
                 raw_input.screen_size = vec2(width as f32, height as f32);
             }
-            MouseInput { state, .. } => {
-                raw_input.mouse_down = state == glutin::ElementState::Pressed;
+            MouseInput {
+                state,
+                button,
+                modifiers,
+                ..
+            } => {
+                let down = state == glutin::ElementState::Pressed;
+                match button {
+                    glutin::MouseButton::Right => raw_input.secondary_down = down,
+                    _ => raw_input.mouse_down = down,
+                }
+                raw_input.modifiers = translate_modifiers(modifiers);
             }
             CursorMoved { position, .. } => {
                 raw_input.mouse_pos = Some(pos2(position.x as f32, position.y as f32));
@@ -50,6 +60,7 @@ pub fn input_event(
                 raw_input.events.push(Event::Text(ch.to_string()));
             }
             KeyboardInput { input, .. } => {
+                raw_input.modifiers = translate_modifiers(input.modifiers);
                 if let Some(virtual_keycode) = input.virtual_keycode {
                     // TODO: If mac
                     if input.modifiers.logo && virtual_keycode == VirtualKeyCode::Q {
@@ -82,10 +93,13 @@ pub fn input_event(
                     }
                 }
             }
-            MouseWheel { delta, .. } => {
+            MouseWheel {
+                delta, modifiers, ..
+            } => {
+                raw_input.modifiers = translate_modifiers(modifiers);
                 match delta {
                     glutin::MouseScrollDelta::LineDelta(x, y) => {
-                        raw_input.scroll_delta = vec2(x, y) * 24.0;
+                        raw_input.scroll_delta_lines = vec2(x, y);
                     }
                     glutin::MouseScrollDelta::PixelDelta(delta) => {
                         // Actually point delta
@@ -133,11 +147,23 @@ pub fn translate_virtual_key_code(key: glutin::VirtualKeyCode) -> Option<emigui:
     })
 }
 
+pub fn translate_modifiers(modifiers: glutin::ModifiersState) -> emigui::Modifiers {
+    emigui::Modifiers {
+        alt: modifiers.alt,
+        ctrl: modifiers.ctrl,
+        shift: modifiers.shift,
+        logo: modifiers.logo,
+    }
+}
+
 pub fn translate_cursor(cursor_icon: emigui::CursorIcon) -> glutin::MouseCursor {
     match cursor_icon {
         CursorIcon::Default => glutin::MouseCursor::Default,
         CursorIcon::PointingHand => glutin::MouseCursor::Hand,
+        CursorIcon::ResizeHorizontal => glutin::MouseCursor::EwResize,
+        CursorIcon::ResizeNeSw => glutin::MouseCursor::NeswResize,
         CursorIcon::ResizeNwSe => glutin::MouseCursor::NwseResize,
+        CursorIcon::ResizeVertical => glutin::MouseCursor::NsResize,
         CursorIcon::Text => glutin::MouseCursor::Text,
     }
 }
@@ -44,6 +44,7 @@ fn main() {
     let mut frame_start = Instant::now();
     let mut frame_times = VecDeque::new();
     let mut example_app = ExampleWindow::default();
+    let mut show_settings = true;
     let mut clipboard = emigui_glium::init_clipboard();
 
     while running {
@@ -59,6 +60,7 @@ fn main() {
         {
             raw_input.time = start_time.elapsed().as_nanos() as f64 * 1e-9;
             raw_input.scroll_delta = vec2(0.0, 0.0);
+            raw_input.scroll_delta_lines = vec2(0.0, 0.0);
             raw_input.dropped_files.clear();
             raw_input.hovered_files.clear();
             raw_input.events.clear();
@@ -85,6 +87,8 @@ fn main() {
             .text_style(TextStyle::Monospace),
         );
 
+        region.add(Checkbox::new(&mut show_settings, "Show settings"));
+
         // TODO: Make it even simpler to show a window
 
         Window::new("Examples")
@@ -97,6 +101,7 @@ fn main() {
             });
 
         Window::new("Emigui settings")
+            .open(&mut show_settings)
             .default_pos(pos2(450.0, 100.0))
             .default_size(vec2(450.0, 500.0))
             .show(region.ctx(), |region| {